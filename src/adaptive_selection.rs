@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::util::Random;
+
+/// Part of the probability which is always spread evenly among all mutators, so that none of them is abandoned.
+const EXPLORATION: f64 = 0.1;
+
+/// Factor by which statistics from previous generations are scaled down every generation.
+const DECAY: f64 = 0.9;
+
+/// Adaptive selection of mutators, modelled as a multi-armed bandit.
+///
+/// Every mutator is picked with probability proportional to its recent rate of successful mutations. Initial weights
+/// act as the prior rate, so they decide until enough statistics are gathered.
+#[derive(Debug, Clone)]
+pub(crate) struct AdaptiveSelection {
+    names: Vec<String>,
+    priors: Vec<f64>,
+    successes: Vec<f64>,
+    trials: Vec<f64>,
+    probabilities: Vec<f64>,
+}
+
+impl AdaptiveSelection {
+    #[must_use]
+    pub fn new(names: Vec<String>, weights: &[f64]) -> Self {
+        assert_eq!(
+            names.len(),
+            weights.len(),
+            "Every mutator must have a weight."
+        );
+        assert!(!names.is_empty(), "There must be at least one mutator.");
+
+        let total = weights.iter().sum::<f64>();
+        let priors = weights.iter().map(|weight| weight / total).collect();
+
+        let mut selection = Self {
+            names,
+            priors,
+            successes: vec![0f64; weights.len()],
+            trials: vec![0f64; weights.len()],
+            probabilities: Vec::new(),
+        };
+        selection.update_probabilities();
+
+        selection
+    }
+
+    fn update_probabilities(&mut self) {
+        let rates = (0..self.names.len())
+            .map(|index| (self.successes[index] + self.priors[index]) / (self.trials[index] + 1f64))
+            .collect::<Vec<f64>>();
+        let total = rates.iter().sum::<f64>();
+        let floor = EXPLORATION / self.names.len() as f64;
+
+        self.probabilities = rates
+            .iter()
+            .map(|rate| floor + (1f64 - EXPLORATION) * rate / total)
+            .collect();
+    }
+
+    /// Returns the index of the mutator which should perform the next mutation.
+    pub fn pick(&self, random: &mut Random) -> usize {
+        let mut threshold = random.get_random(0f64, 1f64);
+
+        self.probabilities
+            .iter()
+            .position(|probability| {
+                threshold -= probability;
+                threshold < 0f64
+            })
+            .unwrap_or(self.probabilities.len() - 1)
+    }
+
+    /// Updates the statistics with outcomes of a single generation, given as pairs of the index of the mutator and
+    /// whether its mutation was successful.
+    pub fn update(&mut self, outcomes: &[(usize, bool)]) {
+        self.successes.iter_mut().for_each(|value| *value *= DECAY);
+        self.trials.iter_mut().for_each(|value| *value *= DECAY);
+
+        for &(index, success) in outcomes {
+            self.trials[index] += 1f64;
+            if success {
+                self.successes[index] += 1f64;
+            }
+        }
+
+        self.update_probabilities();
+    }
+}
+
+impl fmt::Display for AdaptiveSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (name, probability)) in self.names.iter().zip(&self.probabilities).enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {:.2}", name, probability)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    fn get_selection() -> AdaptiveSelection {
+        AdaptiveSelection::new(
+            vec![String::from("Rectangle"), String::from("Triangle")],
+            &[3f64, 1f64],
+        )
+    }
+
+    #[test]
+    fn new_anyWeights_probabilitiesFollowWeights() {
+        let selection = get_selection();
+
+        let probabilities = &selection.probabilities;
+        assert!((probabilities[0] - 0.725).abs() < 1e-9);
+        assert!((probabilities[1] - 0.275).abs() < 1e-9);
+    }
+
+    #[test]
+    fn update_onlyOneMutatorSucceeds_probabilityShiftedTowardsIt() {
+        let mut selection = get_selection();
+
+        for _ in 0..20 {
+            let mut outcomes = vec![(0, false); 10];
+            outcomes.extend(vec![(1, true); 10]);
+            selection.update(&outcomes);
+        }
+
+        let probabilities = &selection.probabilities;
+        assert!(probabilities[1] > 0.9);
+        assert!(probabilities[0] >= EXPLORATION / 2f64);
+    }
+
+    #[test]
+    fn pick_manyPicks_mutatorsPickedAccordingToProbabilities() {
+        let selection = get_selection();
+        let mut random = Random::default();
+
+        let first = (0..4000)
+            .filter(|_| selection.pick(&mut random) == 0)
+            .count();
+
+        assert!((2600..3200).contains(&first));
+    }
+
+    #[test]
+    fn toString_anySelection_probabilitiesListed() {
+        let selection = AdaptiveSelection::new(
+            vec![String::from("Rectangle"), String::from("Triangle")],
+            &[1f64, 1f64],
+        );
+
+        assert_eq!("Rectangle: 0.50, Triangle: 0.50", selection.to_string());
+    }
+}
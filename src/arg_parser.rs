@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use anyhow::Result;
+use anyhow::{Error, Result};
+
+use crate::mutators::{Alpha, Symmetry};
 
 use clap::{
     crate_authors, crate_description, crate_name, crate_version, Arg, ArgMatches, Command,
@@ -12,6 +14,190 @@ const MODE_INFO: &str =
     separately. \"grayscale\" indicates that all color channels should be mutated together, resulting in a grayscale \
     image.";
 
+const MUTATORS: [&str; 12] = [
+    "Rectangle",
+    "Triangle",
+    "Circle",
+    "Ellipse",
+    "Polygon",
+    "Stroke",
+    "Bezier",
+    "Gaussian",
+    "Noise",
+    "Blur",
+    "Stamp",
+    "Halftone",
+];
+
+const MUTATOR_INFO: &str =
+    "Mutator used to mutate specimens. Possible values are: Rectangle, Triangle, Circle, Ellipse, Polygon, Stroke, \
+    Bezier, Gaussian, Noise, Blur, Stamp and Halftone. Several mutators can be combined into a comma-separated list of \
+    mutators with their weights (e.g. Rectangle:0.5,Triangle:0.3,Circle:0.2). Every mutation is then performed by one \
+    of them, picked with probability proportional to its weight. Weight can be omitted, in which case it's 1.";
+
+const ADAPTIVE_MUTATORS_INFO: &str =
+    "Learns which of the mutators listed in mutator work best. Mutators which recently produced better specimens are \
+    picked more often, while weights from the list are only used at the beginning. Current probabilities of all \
+    mutators are printed along with the fitness of every generation.";
+
+const ALPHA_INFO: &str =
+    "Alpha value of shapes drawn by the mutator, where 0 means fully transparent and 255 means fully opaque. Either a \
+    single value (e.g. 128) or an inclusive range (e.g. 64-192) from which the alpha of each shape is picked at \
+    random.";
+
+const ANTI_ALIASING_INFO: &str =
+    "Smooths the edges of shapes drawn by the mutator by blending pixels which are partially covered by the shape. \
+    The generation is slower with this option.";
+
+const POLYGON_KIND_INFO: &str =
+    "Kind of polygons drawn by the Polygon mutator. \"Convex\" draws convex polygons. \"Star\" draws star-shaped \
+    polygons. \"Free\" draws polygons with randomly placed vertices, which may be self-intersecting.";
+
+const POLYGON_VERTICES_INFO: &str =
+    "Number of vertices of polygons drawn by the Polygon mutator. Either a single value (e.g. 5) or an inclusive range \
+    (e.g. 3-8) from which the number of vertices of each polygon is picked at random. Polygons must have at least 3 \
+    vertices.";
+
+const STROKE_WIDTH_INFO: &str =
+    "Width in pixels of strokes drawn by the Stroke and Bezier mutators. Either a single value (e.g. 4) or an \
+    inclusive range (e.g. 1-10) from which the width of each stroke is picked at random. Curves drawn by the Bezier \
+    mutator pick the width of both of their ends separately.";
+
+const STROKE_SEGMENTS_INFO: &str =
+    "Number of segments of strokes drawn by the Stroke mutator. Either a single value (e.g. 1) or an inclusive range \
+    (e.g. 1-4) from which the number of segments of each stroke is picked at random.";
+
+const STROKE_FOLLOW_EDGES_INFO: &str =
+    "Orients strokes drawn by the Stroke mutator along the edges of the source image, instead of orienting them \
+    randomly.";
+
+const BEZIER_DEGREE_INFO: &str =
+    "Degree of curves drawn by the Bezier mutator. \"Quadratic\" curves have 3 control points, while \"Cubic\" \
+    curves have 4 control points.";
+
+const BEZIER_FILLED_INFO: &str =
+    "Makes the Bezier mutator draw filled closed shapes, made of two curves, instead of stroked curves.";
+
+const GAUSSIAN_ANISOTROPIC_INFO: &str =
+    "Makes the Gaussian mutator draw splats stretched in random directions, instead of round splats.";
+
+const NOISE_STRENGTH_INFO: &str =
+    "Standard deviation of Gaussian noise added by the Noise mutator, in units of color channels (0-255). This number \
+    must be positive.";
+
+const BLUR_KIND_INFO: &str =
+    "Kind of blur applied by the Blur mutator. \"Box\" replaces every pixel with the mean color of the square around \
+    it, while \"Gaussian\" weights the mean by the distance from the pixel.";
+
+const BLUR_RADIUS_INFO: &str =
+    "Radius in pixels of blur applied by the Blur mutator. This number must be a positive integer.";
+
+const BRUSHES_INFO: &str =
+    "Path to a directory with brush images pasted by the Stamp mutator. Alpha channel of a brush decides which of its \
+    pixels are pasted. Brushes without alpha channel are pasted where they are dark, weighted by their luminance, in \
+    a single random color.";
+
+const STAMP_KEEP_COLORS_INFO: &str =
+    "Makes the Stamp mutator paste brushes in their original colors, instead of tinting them with random colors.";
+
+const HALFTONE_CELL_SIZE_INFO: &str =
+    "Side in pixels of cells of the grid used by the Halftone mutator, each of which holds a single dot. This number \
+    must be a positive integer.";
+
+const HALFTONE_JITTER_INFO: &str =
+    "How far dots drawn by the Halftone mutator can be moved from the centers of their cells, as a fraction of half \
+    of the cell size. 0 gives a regular grid, like in printed halftone, while 1 gives a pointillist look. This number \
+    must be between 0 and 1.";
+
+const OUTLINE_INFO: &str =
+    "Makes the Rectangle, Triangle, Circle and Polygon mutators draw only outlines of shapes, instead of filling them. \
+    Either a single width in pixels (e.g. 2) or an inclusive range (e.g. 1-4) from which the width of each outline is \
+    picked at random.";
+
+const BLEND_MODE_INFO: &str =
+    "Blend mode by which colors of shapes are combined with colors of specimens underneath them. \"Normal\" replaces \
+    the colors, \"Multiply\" darkens them, \"Screen\" lightens them, \"Add\" and \"Subtract\" add or subtract \
+    channels and \"Difference\" takes the absolute difference of channels. \"Random\" picks one of these modes for \
+    every shape, so the modes evolve along with the shapes.";
+
+const CROSSOVER_INFO: &str =
+    "Crossover function used to breed specimens. \"LeftOrRight\" copies one of the parents, alternating between \
+    them. \"EqualHalfs\" takes the top half of the image from the first parent and the bottom half from the second \
+    one. \"ArithmeticAverage\" averages colors of both parents. \"Uniform\" picks every pixel from a random parent, \
+    see crossover-probability. \"Mask\" takes a random region drawn according to mask-kind from the second parent. \
+    \"Tile\" splits images into tiles, each of which is copied from a random parent, see tile-layout and tile-size.";
+
+const CROSSOVER_PROBABILITY_INFO: &str =
+    "Probability with which the Uniform crossover picks every pixel from the first parent. This number must be \
+    between 0 and 1.";
+
+const MASK_KIND_INFO: &str =
+    "Kind of masks drawn by the Mask crossover to decide which parent every pixel comes from. \"Noise\" splits \
+    images into smooth random blobs, while \"Shape\" takes a random ellipse from the second parent.";
+
+const TILE_LAYOUT_INFO: &str =
+    "How the Tile crossover splits images into tiles, each of which is copied from one of the parents. \"Grid\" uses \
+    square tiles of equal size, while \"Regions\" uses rectangles of random sizes, separated by a few random cuts \
+    along both axes.";
+
+const TILE_SIZE_INFO: &str =
+    "Side in pixels of tiles used by the Tile crossover with the \"Grid\" layout. This number must be a positive \
+    integer. If it's omitted, every crossover picks a random size.";
+
+const FILL_INFO: &str =
+    "Fill of shapes drawn by the Rectangle, Triangle and Circle mutators. \"LinearGradient\" and \"RadialGradient\" \
+    fill shapes with gradients between random points, passing through random colors.";
+
+const GRADIENT_STOPS_INFO: &str =
+    "Number of colors of gradients used when fill is either \"LinearGradient\" or \"RadialGradient\". This number must \
+    be an integer greater than 1.";
+
+const COLOR_STRATEGY_INFO: &str =
+    "Strategy used to choose colors of new shapes. \"Random\" picks colors at random, \"Mean\" uses the mean color of \
+    the source image under the shape and \"LeastSquares\" uses the color which brings the specimen closest to the \
+    source image.";
+
+const PALETTE_INFO: &str =
+    "Path to a palette which restricts colors of shapes. The palette must be either a GIMP palette (GPL) or a list of \
+    hex color codes (e.g. #ff8800), separated by whitespace. The palette is saved along with the specimens. This \
+    option conflicts with palette-size. Only one of them can be used at the same time.";
+
+const PALETTE_SIZE_INFO: &str =
+    "Restricts colors of shapes to a palette of N colors extracted from the source image with k-means clustering. The \
+    palette is saved along with the specimens. This option conflicts with palette. Only one of them can be used at \
+    the same time.";
+
+const SIZE_SCHEDULE_INFO: &str =
+    "Schedule of the maximal size of shapes drawn by all mutators. \"Constant\" keeps the size at max-size. \
+    \"Generations\" halves the size every size-decay generations. \"Plateau\" halves the size every time size-decay \
+    more generations fail to improve the best specimen. The size never drops below min-size.";
+
+const MAX_SIZE_INFO: &str =
+    "Maximal size of shapes in the first generation, as a fraction of the largest shape which fits the image. This \
+    number must be greater than 0 and at most 1.";
+
+const MIN_SIZE_INFO: &str =
+    "The lowest maximal size of shapes, as a fraction of the largest shape which fits the image. This number must be \
+    greater than 0 and at most 1.";
+
+const SIZE_DECAY_INFO: &str =
+    "Number of generations after which the maximal size of shapes is halved, according to size-schedule. This number \
+    must be a positive integer.";
+
+const PLACEMENT_INFO: &str =
+    "Placement of shapes drawn by all mutators. \"Uniform\" places shapes anywhere in the image with equal \
+    probability. \"ErrorGuided\" places shapes where the best specimen from the previous generation differs the most \
+    from the source image.";
+
+const SYMMETRY_INFO: &str =
+    "Symmetry enforced on shapes drawn by all mutators. \"Horizontal\" mirrors shapes between the left and the right \
+    half of the image, \"Vertical\" between the top and the bottom half, and \"Both\" does both. \"Rotational:N\" \
+    (e.g. Rotational:6) repeats shapes N times around the center of the image, where N is between 2 and 64.";
+
+const SEED_INFO: &str =
+    "Seed of the random number generator. Simulations with the same seed and arguments produce the same specimens. \
+    The seed must be a non-negative integer.";
+
 const THREADS_INFO: &str =
     "Number of working threads used to mutate specimens and calculate their fitness. This number must be a positive \
     integer.";
@@ -66,6 +252,13 @@ fn validate_threads(s: &str) -> Result<(), String> {
     }
 }
 
+fn validate_seed(s: &str) -> Result<(), String> {
+    match s.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(String::from("Seed must be a non-negative integer.")),
+    }
+}
+
 fn validate_every(s: &str) -> Result<(), String> {
     match s.parse::<u32>() {
         Ok(size) => {
@@ -79,6 +272,230 @@ fn validate_every(s: &str) -> Result<(), String> {
     }
 }
 
+fn validate_alpha(s: &str) -> Result<(), String> {
+    match s.parse::<Alpha>() {
+        Ok(_) => Ok(()),
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_noise_strength(s: &str) -> Result<(), String> {
+    match s.parse::<f64>() {
+        Ok(strength) => {
+            if strength.is_finite() && strength > 0f64 {
+                Ok(())
+            } else {
+                Err(String::from("Strength of noise must be a positive number."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_blur_radius(s: &str) -> Result<(), String> {
+    match s.parse::<usize>() {
+        Ok(radius) => {
+            if radius > 0 {
+                Ok(())
+            } else {
+                Err(String::from("Radius of blur must be a positive integer."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_halftone_cell_size(s: &str) -> Result<(), String> {
+    match s.parse::<usize>() {
+        Ok(size) => {
+            if size > 0 {
+                Ok(())
+            } else {
+                Err(String::from("Size of a cell must be a positive integer."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_halftone_jitter(s: &str) -> Result<(), String> {
+    match s.parse::<f64>() {
+        Ok(jitter) => {
+            if (0f64..=1f64).contains(&jitter) {
+                Ok(())
+            } else {
+                Err(String::from("Jitter must be a number between 0 and 1."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_crossover_probability(s: &str) -> Result<(), String> {
+    match s.parse::<f64>() {
+        Ok(probability) => {
+            if (0f64..=1f64).contains(&probability) {
+                Ok(())
+            } else {
+                Err(String::from(
+                    "Probability must be a number between 0 and 1.",
+                ))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_tile_size(s: &str) -> Result<(), String> {
+    match s.parse::<usize>() {
+        Ok(size) => {
+            if size > 0 {
+                Ok(())
+            } else {
+                Err(String::from("Size of a tile must be a positive integer."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_symmetry(s: &str) -> Result<(), String> {
+    match s.parse::<Symmetry>() {
+        Ok(_) => Ok(()),
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn parse_range(s: &str) -> Result<(usize, usize)> {
+    let (min, max) = match s.split_once('-') {
+        Some((min, max)) => (min.trim().parse::<usize>()?, max.trim().parse::<usize>()?),
+        None => {
+            let value = s.trim().parse::<usize>()?;
+            (value, value)
+        }
+    };
+
+    if min > max {
+        return Err(Error::msg(
+            "The lower bound of the range cannot be greater than the upper bound.",
+        ));
+    }
+
+    Ok((min, max))
+}
+
+fn parse_weighted_values(s: &str) -> Result<Vec<(String, f64)>> {
+    s.split(',')
+        .map(|entry| {
+            let (name, weight) = match entry.split_once(':') {
+                Some((name, weight)) => (name.trim(), weight.trim().parse::<f64>()?),
+                None => (entry.trim(), 1f64),
+            };
+
+            if !weight.is_finite() || weight <= 0f64 {
+                return Err(Error::msg("Weights must be positive numbers."));
+            }
+
+            Ok((name.to_owned(), weight))
+        })
+        .collect()
+}
+
+fn validate_mutator(s: &str) -> Result<(), String> {
+    match parse_weighted_values(s) {
+        Ok(mutators) => match mutators
+            .iter()
+            .find(|(name, _)| !MUTATORS.contains(&name.as_str()))
+        {
+            Some((name, _)) => Err(format!("Unknown mutator: {}.", name)),
+            None => Ok(()),
+        },
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_polygon_vertices(s: &str) -> Result<(), String> {
+    match parse_range(s) {
+        Ok((min, _)) => {
+            if min > 2 {
+                Ok(())
+            } else {
+                Err(String::from("Polygon must have at least 3 vertices."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_positive_range(s: &str) -> Result<(), String> {
+    match parse_range(s) {
+        Ok((min, _)) => {
+            if min > 0 {
+                Ok(())
+            } else {
+                Err(String::from(
+                    "Values of the range must be positive integers.",
+                ))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_gradient_stops(s: &str) -> Result<(), String> {
+    match s.parse::<usize>() {
+        Ok(stops) => {
+            if stops > 1 {
+                Ok(())
+            } else {
+                Err(String::from("Gradient must have at least 2 colors."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_palette_size(s: &str) -> Result<(), String> {
+    match s.parse::<usize>() {
+        Ok(size) => {
+            if size > 0 {
+                Ok(())
+            } else {
+                Err(String::from("Palette must have at least 1 color."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_size(s: &str) -> Result<(), String> {
+    match s.parse::<f64>() {
+        Ok(size) => {
+            if size > 0f64 && size <= 1f64 {
+                Ok(())
+            } else {
+                Err(String::from(
+                    "Size must be a number greater than 0 and at most 1.",
+                ))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+fn validate_size_decay(s: &str) -> Result<(), String> {
+    match s.parse::<u32>() {
+        Ok(decay) => {
+            if decay > 0 {
+                Ok(())
+            } else {
+                Err(String::from("Size decay must be a positive integer."))
+            }
+        }
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
 fn get_app() -> Command<'static> {
     Command::new(crate_name!())
         .author(crate_authors!("\n"))
@@ -93,7 +510,7 @@ fn get_app() -> Command<'static> {
                 .required(true)
                 .forbid_empty_values(true)
                 .value_hint(ValueHint::FilePath)
-                .display_order(10),
+                .display_order(100),
         )
         .arg(
             Arg::new("color_mode")
@@ -102,294 +519,1519 @@ fn get_app() -> Command<'static> {
                 .takes_value(true)
                 .possible_values(["Rgb", "Grayscale"])
                 .default_value("Rgb")
-                .display_order(20),
+                .display_order(200),
         )
         .arg(
             Arg::new("mutator")
                 .short('m')
                 .long("mutator")
-                .help("Mutator used to mutate specimens")
+                .long_help(MUTATOR_INFO)
                 .takes_value(true)
-                .possible_values(["Rectangle", "Triangle", "Circle"])
+                .validator(validate_mutator)
                 .default_value("Rectangle")
-                .display_order(30),
+                .display_order(300),
         )
         .arg(
-            Arg::new("fitness")
-                .short('f')
-                .long("fitness")
-                .help("Fitness function used to calculate fitness of specimens")
+            Arg::new("adaptive_mutators")
+                .long("adaptive-mutators")
+                .long_help(ADAPTIVE_MUTATORS_INFO)
+                .takes_value(false)
+                .display_order(305),
+        )
+        .arg(
+            Arg::new("symmetry")
+                .long("symmetry")
+                .long_help(SYMMETRY_INFO)
                 .takes_value(true)
-                .possible_values(["SquareDistance", "AbsoluteDistance"])
-                .default_value("SquareDistance")
-                .display_order(40),
+                .validator(validate_symmetry)
+                .display_order(310),
         )
         .arg(
-            Arg::new("crossover")
-                .short('c')
-                .long("crossover")
-                .help("Crossover function used to breed specimens")
+            Arg::new("alpha")
+                .long("alpha")
+                .long_help(ALPHA_INFO)
                 .takes_value(true)
-                .possible_values(["LeftOrRight", "EqualHalfs", "ArithmeticAverage"])
-                .default_value("LeftOrRight")
-                .display_order(50),
+                .forbid_empty_values(true)
+                .default_value("255")
+                .value_name("ALPHA")
+                .validator(validate_alpha)
+                .display_order(320),
         )
         .arg(
-            Arg::new("generation_size")
-                .short('g')
-                .long("generation")
-                .help("Number of specimens in each generation")
+            Arg::new("anti_aliasing")
+                .long("anti-aliasing")
+                .long_help(ANTI_ALIASING_INFO)
+                .takes_value(false)
+                .display_order(321),
+        )
+        .arg(
+            Arg::new("polygon_kind")
+                .long("polygon-kind")
+                .long_help(POLYGON_KIND_INFO)
+                .takes_value(true)
+                .possible_values(["Convex", "Star", "Free"])
+                .default_value("Convex")
+                .display_order(330),
+        )
+        .arg(
+            Arg::new("polygon_vertices")
+                .long("polygon-vertices")
+                .long_help(POLYGON_VERTICES_INFO)
                 .takes_value(true)
-                .default_value("100")
                 .forbid_empty_values(true)
+                .default_value("3-8")
                 .value_name("N")
-                .validator(validate_generation_size)
-                .display_order(60),
+                .validator(validate_polygon_vertices)
+                .display_order(331),
         )
         .arg(
-            Arg::new("threads")
-                .short('t')
-                .long("threads")
-                .long_help(THREADS_INFO)
+            Arg::new("stroke_width")
+                .long("stroke-width")
+                .long_help(STROKE_WIDTH_INFO)
+                .takes_value(true)
+                .forbid_empty_values(true)
+                .default_value("1-10")
+                .value_name("N")
+                .validator(validate_positive_range)
+                .display_order(335),
+        )
+        .arg(
+            Arg::new("stroke_segments")
+                .long("stroke-segments")
+                .long_help(STROKE_SEGMENTS_INFO)
                 .takes_value(true)
                 .forbid_empty_values(true)
                 .default_value("1")
                 .value_name("N")
-                .validator(validate_threads)
-                .display_order(70),
+                .validator(validate_positive_range)
+                .display_order(336),
         )
         .arg(
-            Arg::new("display_all")
-                .long("display-all")
-                .long_help(DISPLAY_ALL_INFO)
+            Arg::new("stroke_follow_edges")
+                .long("stroke-follow-edges")
+                .long_help(STROKE_FOLLOW_EDGES_INFO)
                 .takes_value(false)
-                .group("display")
-                .display_order(80),
+                .display_order(337),
         )
         .arg(
-            Arg::new("display_every")
-                .long("display-every")
-                .long_help(DISPLAY_EVERY_INFO)
+            Arg::new("bezier_degree")
+                .long("bezier-degree")
+                .long_help(BEZIER_DEGREE_INFO)
                 .takes_value(true)
-                .forbid_empty_values(true)
+                .possible_values(["Quadratic", "Cubic"])
+                .default_value("Cubic")
+                .display_order(340),
+        )
+        .arg(
+            Arg::new("bezier_filled")
+                .long("bezier-filled")
+                .long_help(BEZIER_FILLED_INFO)
+                .takes_value(false)
+                .display_order(341),
+        )
+        .arg(
+            Arg::new("gaussian_anisotropic")
+                .long("gaussian-anisotropic")
+                .long_help(GAUSSIAN_ANISOTROPIC_INFO)
+                .takes_value(false)
+                .display_order(345),
+        )
+        .arg(
+            Arg::new("noise_strength")
+                .long("noise-strength")
+                .long_help(NOISE_STRENGTH_INFO)
+                .takes_value(true)
+                .validator(validate_noise_strength)
+                .default_value("16")
+                .display_order(350),
+        )
+        .arg(
+            Arg::new("blur_kind")
+                .long("blur-kind")
+                .long_help(BLUR_KIND_INFO)
+                .takes_value(true)
+                .possible_values(["Box", "Gaussian"])
+                .default_value("Box")
+                .display_order(355),
+        )
+        .arg(
+            Arg::new("blur_radius")
+                .long("blur-radius")
+                .long_help(BLUR_RADIUS_INFO)
+                .takes_value(true)
+                .validator(validate_blur_radius)
+                .default_value("1")
                 .value_name("N")
-                .group("display")
-                .validator(validate_every)
-                .display_order(90),
+                .display_order(356),
         )
         .arg(
-            Arg::new("output_directory")
-                .long("output-dir")
-                .long_help(OUTPUT_DIRECTORY_INFO)
+            Arg::new("brushes")
+                .long("brushes")
+                .long_help(BRUSHES_INFO)
                 .takes_value(true)
                 .forbid_empty_values(true)
                 .value_name("DIR")
                 .value_hint(ValueHint::DirPath)
-                .display_order(100),
+                .display_order(360),
         )
         .arg(
-            Arg::new("filename_prefix")
-                .long("filename-prefix")
-                .long_help(FILENAME_PREFIX_INFO)
+            Arg::new("stamp_keep_colors")
+                .long("stamp-keep-colors")
+                .long_help(STAMP_KEEP_COLORS_INFO)
+                .takes_value(false)
+                .display_order(361),
+        )
+        .arg(
+            Arg::new("halftone_cell_size")
+                .long("halftone-cell-size")
+                .long_help(HALFTONE_CELL_SIZE_INFO)
                 .takes_value(true)
-                .forbid_empty_values(true)
-                .default_value("output_")
-                .value_name("PREFIX")
-                .display_order(110),
+                .validator(validate_halftone_cell_size)
+                .default_value("8")
+                .value_name("N")
+                .display_order(365),
         )
         .arg(
-            Arg::new("save_all")
-                .long("save-all")
-                .long_help(SAVE_ALL_INFO)
-                .takes_value(false)
-                .group("output")
-                .display_order(120),
+            Arg::new("halftone_jitter")
+                .long("halftone-jitter")
+                .long_help(HALFTONE_JITTER_INFO)
+                .takes_value(true)
+                .validator(validate_halftone_jitter)
+                .default_value("0")
+                .display_order(366),
         )
         .arg(
-            Arg::new("save_every")
-                .long("save-every")
-                .long_help(SAVE_EVERY_INFO)
+            Arg::new("outline")
+                .long("outline")
+                .long_help(OUTLINE_INFO)
                 .takes_value(true)
                 .forbid_empty_values(true)
                 .value_name("N")
-                .group("output")
-                .validator(validate_every)
-                .display_order(130),
+                .validator(validate_positive_range)
+                .display_order(370),
         )
-}
-
-#[derive(Debug)]
-#[doc(hidden)]
-pub struct ArgParser {
-    arg_matches: ArgMatches,
-}
+        .arg(
+            Arg::new("blend_mode")
+                .long("blend-mode")
+                .long_help(BLEND_MODE_INFO)
+                .takes_value(true)
+                .possible_values([
+                    "Normal",
+                    "Multiply",
+                    "Screen",
+                    "Add",
+                    "Subtract",
+                    "Difference",
+                    "Random",
+                ])
+                .default_value("Normal")
+                .display_order(371),
+        )
+        .arg(
+            Arg::new("fill")
+                .long("fill")
+                .long_help(FILL_INFO)
+                .takes_value(true)
+                .possible_values(["Flat", "LinearGradient", "RadialGradient"])
+                .default_value("Flat")
+                .display_order(372),
+        )
+        .arg(
+            Arg::new("gradient_stops")
+                .long("gradient-stops")
+                .long_help(GRADIENT_STOPS_INFO)
+                .takes_value(true)
+                .validator(validate_gradient_stops)
+                .default_value("2")
+                .display_order(373),
+        )
+        .arg(
+            Arg::new("color_strategy")
+                .long("color-strategy")
+                .long_help(COLOR_STRATEGY_INFO)
+                .takes_value(true)
+                .possible_values(["Random", "Mean", "LeastSquares"])
+                .default_value("Random")
+                .display_order(374),
+        )
+        .arg(
+            Arg::new("palette")
+                .long("palette")
+                .long_help(PALETTE_INFO)
+                .takes_value(true)
+                .conflicts_with("palette_size")
+                .display_order(380),
+        )
+        .arg(
+            Arg::new("palette_size")
+                .long("palette-size")
+                .long_help(PALETTE_SIZE_INFO)
+                .takes_value(true)
+                .validator(validate_palette_size)
+                .display_order(381),
+        )
+        .arg(
+            Arg::new("size_schedule")
+                .long("size-schedule")
+                .long_help(SIZE_SCHEDULE_INFO)
+                .takes_value(true)
+                .possible_values(["Constant", "Generations", "Plateau"])
+                .default_value("Constant")
+                .display_order(385),
+        )
+        .arg(
+            Arg::new("max_size")
+                .long("max-size")
+                .long_help(MAX_SIZE_INFO)
+                .takes_value(true)
+                .validator(validate_size)
+                .default_value("1")
+                .display_order(386),
+        )
+        .arg(
+            Arg::new("min_size")
+                .long("min-size")
+                .long_help(MIN_SIZE_INFO)
+                .takes_value(true)
+                .validator(validate_size)
+                .default_value("0.05")
+                .display_order(387),
+        )
+        .arg(
+            Arg::new("size_decay")
+                .long("size-decay")
+                .long_help(SIZE_DECAY_INFO)
+                .takes_value(true)
+                .validator(validate_size_decay)
+                .default_value("100")
+                .value_name("N")
+                .display_order(388),
+        )
+        .arg(
+            Arg::new("placement")
+                .long("placement")
+                .long_help(PLACEMENT_INFO)
+                .takes_value(true)
+                .possible_values(["Uniform", "ErrorGuided"])
+                .default_value("Uniform")
+                .display_order(390),
+        )
+        .arg(
+            Arg::new("fitness")
+                .short('f')
+                .long("fitness")
+                .help("Fitness function used to calculate fitness of specimens")
+                .takes_value(true)
+                .possible_values(["SquareDistance", "AbsoluteDistance"])
+                .default_value("SquareDistance")
+                .display_order(400),
+        )
+        .arg(
+            Arg::new("crossover")
+                .short('c')
+                .long("crossover")
+                .long_help(CROSSOVER_INFO)
+                .takes_value(true)
+                .possible_values([
+                    "LeftOrRight",
+                    "EqualHalfs",
+                    "ArithmeticAverage",
+                    "Uniform",
+                    "Mask",
+                    "Tile",
+                ])
+                .default_value("LeftOrRight")
+                .display_order(500),
+        )
+        .arg(
+            Arg::new("crossover_probability")
+                .long("crossover-probability")
+                .long_help(CROSSOVER_PROBABILITY_INFO)
+                .takes_value(true)
+                .validator(validate_crossover_probability)
+                .default_value("0.5")
+                .display_order(510),
+        )
+        .arg(
+            Arg::new("tile_layout")
+                .long("tile-layout")
+                .long_help(TILE_LAYOUT_INFO)
+                .takes_value(true)
+                .possible_values(["Grid", "Regions"])
+                .default_value("Grid")
+                .display_order(530),
+        )
+        .arg(
+            Arg::new("tile_size")
+                .long("tile-size")
+                .long_help(TILE_SIZE_INFO)
+                .takes_value(true)
+                .validator(validate_tile_size)
+                .value_name("N")
+                .display_order(531),
+        )
+        .arg(
+            Arg::new("mask_kind")
+                .long("mask-kind")
+                .long_help(MASK_KIND_INFO)
+                .takes_value(true)
+                .possible_values(["Noise", "Shape"])
+                .default_value("Noise")
+                .display_order(520),
+        )
+        .arg(
+            Arg::new("generation_size")
+                .short('g')
+                .long("generation")
+                .help("Number of specimens in each generation")
+                .takes_value(true)
+                .default_value("100")
+                .forbid_empty_values(true)
+                .value_name("N")
+                .validator(validate_generation_size)
+                .display_order(600),
+        )
+        .arg(
+            Arg::new("threads")
+                .short('t')
+                .long("threads")
+                .long_help(THREADS_INFO)
+                .takes_value(true)
+                .forbid_empty_values(true)
+                .default_value("1")
+                .value_name("N")
+                .validator(validate_threads)
+                .display_order(700),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .long_help(SEED_INFO)
+                .takes_value(true)
+                .value_name("SEED")
+                .validator(validate_seed)
+                .display_order(750),
+        )
+        .arg(
+            Arg::new("display_all")
+                .long("display-all")
+                .long_help(DISPLAY_ALL_INFO)
+                .takes_value(false)
+                .group("display")
+                .display_order(800),
+        )
+        .arg(
+            Arg::new("display_every")
+                .long("display-every")
+                .long_help(DISPLAY_EVERY_INFO)
+                .takes_value(true)
+                .forbid_empty_values(true)
+                .value_name("N")
+                .group("display")
+                .validator(validate_every)
+                .display_order(900),
+        )
+        .arg(
+            Arg::new("output_directory")
+                .long("output-dir")
+                .long_help(OUTPUT_DIRECTORY_INFO)
+                .takes_value(true)
+                .forbid_empty_values(true)
+                .value_name("DIR")
+                .value_hint(ValueHint::DirPath)
+                .display_order(1000),
+        )
+        .arg(
+            Arg::new("filename_prefix")
+                .long("filename-prefix")
+                .long_help(FILENAME_PREFIX_INFO)
+                .takes_value(true)
+                .forbid_empty_values(true)
+                .default_value("output_")
+                .value_name("PREFIX")
+                .display_order(1100),
+        )
+        .arg(
+            Arg::new("save_all")
+                .long("save-all")
+                .long_help(SAVE_ALL_INFO)
+                .takes_value(false)
+                .group("output")
+                .display_order(1200),
+        )
+        .arg(
+            Arg::new("save_every")
+                .long("save-every")
+                .long_help(SAVE_EVERY_INFO)
+                .takes_value(true)
+                .forbid_empty_values(true)
+                .value_name("N")
+                .group("output")
+                .validator(validate_every)
+                .display_order(1300),
+        )
+}
+
+#[derive(Debug)]
+#[doc(hidden)]
+pub struct ArgParser {
+    arg_matches: ArgMatches,
+}
+
+impl ArgParser {
+    #[must_use]
+    pub fn get_value(&self, key: &str) -> Option<&str> {
+        self.arg_matches.value_of(key)
+    }
+
+    pub fn get_value_t<T>(&self, key: &str) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        Ok(self.arg_matches.value_of_t::<T>(key)?)
+    }
+
+    pub fn get_range(&self, key: &str) -> Result<(usize, usize)> {
+        match self.arg_matches.value_of(key) {
+            Some(value) => parse_range(value),
+            None => Err(Error::msg(format!("Argument \"{}\" is not present.", key))),
+        }
+    }
+
+    pub fn get_weighted_values(&self, key: &str) -> Result<Vec<(String, f64)>> {
+        match self.arg_matches.value_of(key) {
+            Some(value) => parse_weighted_values(value),
+            None => Err(Error::msg(format!("Argument \"{}\" is not present.", key))),
+        }
+    }
+
+    #[must_use]
+    pub fn is_present(&self, key: &str) -> bool {
+        self.arg_matches.is_present(key)
+    }
+}
+
+impl Default for ArgParser {
+    fn default() -> Self {
+        Self {
+            arg_matches: get_app().get_matches(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn image_wasNotPassed_validationFailed() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn image_valueIsEmpty_validationFailed() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", ""]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn image_valueIsNotEmpty_validationPassed() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn image_shortNameGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "-i", "PATH"]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn image_valueGiven_correctValueReturned() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "-i", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("PATH", result.value_of("image").unwrap_or_default());
+    }
+
+    #[test]
+    fn mode_modeIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Rgb", result.value_of("color_mode").unwrap_or_default());
+    }
+
+    #[test]
+    fn mode_modeIsSpecified_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mode",
+            "Grayscale",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "Grayscale",
+            result.value_of("color_mode").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn mode_valueDoesNotExistInPossibleValues_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mode",
+            "FOO",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mutator_mutatorIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Rectangle", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn mutator_mutatorIsSpecified_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mutator",
+            "Rectangle",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Rectangle", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn mutator_shortNameGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Triangle",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Triangle", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn mutator_valueDoesNotExistInPossibleValues_validationFailed() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "FOO"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mutator_weightedListGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mutator",
+            "Rectangle:0.5,Triangle:0.3,Circle:0.2",
+        ]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn mutator_listWithUnknownMutator_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mutator",
+            "Rectangle:0.5,FOO:0.5",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mutator_nonPositiveWeight_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mutator",
+            "Rectangle:0,Triangle:1",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn adaptiveMutators_adaptiveMutatorsGiven_adaptiveMutatorsIsPresent() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--mutator",
+            "Rectangle,Triangle",
+            "--adaptive-mutators",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert!(result.is_present("adaptive_mutators"));
+    }
+
+    #[test]
+    fn parseWeightedValues_weightOmitted_defaultWeightUsed() {
+        let values = parse_weighted_values("Rectangle, Triangle:2.5").unwrap();
+
+        assert_eq!(
+            vec![
+                (String::from("Rectangle"), 1f64),
+                (String::from("Triangle"), 2.5)
+            ],
+            values
+        );
+    }
+
+    #[test]
+    fn mutator_circlePassedAsValue_validationPassed() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "Circle"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Circle", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn mutator_ellipsePassedAsValue_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Ellipse",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Ellipse", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn mutator_polygonPassedAsValue_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Polygon",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Polygon", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn polygonKind_polygonKindIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "Convex",
+            result.value_of("polygon_kind").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn polygonKind_polygonKindIsSpecified_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--polygon-kind",
+            "Free",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Free", result.value_of("polygon_kind").unwrap_or_default());
+    }
+
+    #[test]
+    fn polygonKind_valueDoesNotExistInPossibleValues_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--polygon-kind",
+            "FOO",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn polygonVertices_polygonVerticesIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "3-8",
+            result.value_of("polygon_vertices").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn polygonVertices_singleValueGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--polygon-vertices",
+            "5",
+        ]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn polygonVertices_rangeGiven_rangeParsed() {
+        assert_eq!((4, 10), parse_range("4-10").unwrap());
+        assert_eq!((5, 5), parse_range("5").unwrap());
+    }
+
+    #[test]
+    fn polygonVertices_valueSmallerThan3_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--polygon-vertices",
+            "2-5",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn polygonVertices_lowerBoundGreaterThanUpperBound_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--polygon-vertices",
+            "8-3",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mutator_strokePassedAsValue_validationPassed() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "Stroke"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Stroke", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn strokeWidth_strokeWidthIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("1-10", result.value_of("stroke_width").unwrap_or_default());
+    }
+
+    #[test]
+    fn strokeWidth_rangeGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--stroke-width",
+            "2-6",
+        ]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn strokeWidth_valueEqualTo0_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--stroke-width",
+            "0-6",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn strokeSegments_strokeSegmentsIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("1", result.value_of("stroke_segments").unwrap_or_default());
+    }
+
+    #[test]
+    fn strokeSegments_valueIsNotNumber_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--stroke-segments",
+            "FOO",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn strokeFollowEdges_strokeFollowEdgesGiven_strokeFollowEdgesIsPresent() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--stroke-follow-edges",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert!(result.is_present("stroke_follow_edges"));
+    }
+
+    #[test]
+    fn mutator_bezierPassedAsValue_validationPassed() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "Bezier"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Bezier", result.value_of("mutator").unwrap_or_default());
+    }
+
+    #[test]
+    fn mutator_gaussianPassedAsValue_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Gaussian",
+            "--gaussian-anisotropic",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Gaussian", result.value_of("mutator").unwrap_or_default());
+        assert!(result.is_present("gaussian_anisotropic"));
+    }
+
+    #[test]
+    fn bezierDegree_bezierDegreeIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "Cubic",
+            result.value_of("bezier_degree").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn bezierDegree_bezierDegreeIsSpecified_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--bezier-degree",
+            "Quadratic",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "Quadratic",
+            result.value_of("bezier_degree").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn bezierDegree_valueDoesNotExistInPossibleValues_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--bezier-degree",
+            "Quartic",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bezierFilled_bezierFilledGiven_bezierFilledIsPresent() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--bezier-filled",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert!(result.is_present("bezier_filled"));
+    }
+
+    #[test]
+    fn fill_fillIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Flat", result.value_of("fill").unwrap_or_default());
+    }
+
+    #[test]
+    fn fill_radialGradientGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--fill",
+            "RadialGradient",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "RadialGradient",
+            result.value_of("fill").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn fill_valueDoesNotExistInPossibleValues_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--fill",
+            "Pattern",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn gradientStops_gradientStopsIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("2", result.value_of("gradient_stops").unwrap_or_default());
+    }
+
+    #[test]
+    fn gradientStops_singleStopGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--gradient-stops",
+            "1",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn colorStrategy_colorStrategyIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "Random",
+            result.value_of("color_strategy").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn colorStrategy_leastSquaresGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--color-strategy",
+            "LeastSquares",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "LeastSquares",
+            result.value_of("color_strategy").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn colorStrategy_valueDoesNotExistInPossibleValues_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--color-strategy",
+            "Median",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn palette_paletteGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--palette",
+            "PALETTE",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("PALETTE", result.value_of("palette").unwrap_or_default());
+    }
+
+    #[test]
+    fn paletteSize_zeroGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--palette-size",
+            "0",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn paletteSize_paletteAlsoGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--palette",
+            "PALETTE",
+            "--palette-size",
+            "8",
+        ]);
 
-impl ArgParser {
-    #[must_use]
-    pub fn get_value(&self, key: &str) -> Option<&str> {
-        self.arg_matches.value_of(key)
+        assert!(result.is_err());
     }
 
-    pub fn get_value_t<T>(&self, key: &str) -> Result<T>
-    where
-        T: FromStr,
-        <T as FromStr>::Err: Display,
-    {
-        Ok(self.arg_matches.value_of_t::<T>(key)?)
-    }
+    #[test]
+    fn sizeSchedule_sizeScheduleIsNotSpecified_defaultValuesSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
 
-    #[must_use]
-    pub fn is_present(&self, key: &str) -> bool {
-        self.arg_matches.is_present(key)
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(
+            "Constant",
+            result.value_of("size_schedule").unwrap_or_default()
+        );
+        assert_eq!("1", result.value_of("max_size").unwrap_or_default());
+        assert_eq!("0.05", result.value_of("min_size").unwrap_or_default());
+        assert_eq!("100", result.value_of("size_decay").unwrap_or_default());
     }
-}
 
-impl Default for ArgParser {
-    #[must_use]
-    fn default() -> Self {
-        Self {
-            arg_matches: get_app().get_matches(),
+    #[test]
+    fn maxSize_sizeOutOfRangeGiven_validationFailed() {
+        for size in ["0", "1.5", "-0.5", "abc"] {
+            let result = get_app().try_get_matches_from(vec![
+                "franklin-cli",
+                "--image",
+                "PATH",
+                "--max-size",
+                size,
+            ]);
+
+            assert!(result.is_err());
         }
     }
-}
-
-#[cfg(test)]
-#[allow(non_snake_case)]
-mod test {
-    use super::*;
 
     #[test]
-    fn image_wasNotPassed_validationFailed() {
-        let result = get_app().try_get_matches_from(vec!["franklin-cli"]);
+    fn sizeDecay_zeroGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--size-schedule",
+            "Plateau",
+            "--size-decay",
+            "0",
+        ]);
 
         assert!(result.is_err());
     }
 
     #[test]
-    fn image_valueIsEmpty_validationFailed() {
-        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", ""]);
+    fn placement_placementIsNotSpecified_defaultValueSet() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Uniform", result.value_of("placement").unwrap_or_default());
+    }
+
+    #[test]
+    fn noiseStrength_nonPositiveStrengthGiven_validationFailed() {
+        for strength in ["0", "-4", "abc"] {
+            let result = get_app().try_get_matches_from(vec![
+                "franklin-cli",
+                "--image",
+                "PATH",
+                "--noise-strength",
+                strength,
+            ]);
+
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn blurRadius_zeroGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Blur",
+            "--blur-radius",
+            "0",
+        ]);
 
         assert!(result.is_err());
     }
 
     #[test]
-    fn image_valueIsNotEmpty_validationPassed() {
-        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
+    fn blurKind_blurKindIsNotSpecified_defaultValueSet() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "Blur"]);
 
         assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Box", result.value_of("blur_kind").unwrap_or_default());
+        assert_eq!("1", result.value_of("blur_radius").unwrap_or_default());
     }
 
     #[test]
-    fn image_shortNameGiven_validationPassed() {
-        let result = get_app().try_get_matches_from(vec!["franklin-cli", "-i", "PATH"]);
+    fn brushes_brushesGiven_valueSet() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Stamp",
+            "--brushes",
+            "DIR",
+            "--stamp-keep-colors",
+        ]);
 
         assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("DIR", result.value_of("brushes").unwrap_or_default());
+        assert!(result.is_present("stamp_keep_colors"));
     }
 
     #[test]
-    fn image_valueGiven_correctValueReturned() {
-        let result = get_app().try_get_matches_from(vec!["franklin-cli", "-i", "PATH"]);
+    fn halftoneCellSize_zeroGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Halftone",
+            "--halftone-cell-size",
+            "0",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn halftoneJitter_outOfRangeGiven_validationFailed() {
+        for jitter in ["-0.5", "1.5", "abc"] {
+            let result = get_app().try_get_matches_from(vec![
+                "franklin-cli",
+                "--image",
+                "PATH",
+                "-m",
+                "Halftone",
+                "--halftone-jitter",
+                jitter,
+            ]);
+
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn halftone_optionsAreNotSpecified_defaultValuesSet() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-m",
+            "Halftone",
+        ]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
-        assert_eq!("PATH", result.value_of("image").unwrap_or_default());
+        assert_eq!(
+            "8",
+            result.value_of("halftone_cell_size").unwrap_or_default()
+        );
+        assert_eq!("0", result.value_of("halftone_jitter").unwrap_or_default());
     }
 
     #[test]
-    fn mode_modeIsNotSpecified_defaultValueSet() {
+    fn outline_rangeGiven_valueSet() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--outline",
+            "1-4",
+        ]);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            "1-4",
+            result.unwrap().value_of("outline").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn outline_zeroWidthGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--outline",
+            "0",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn blendMode_blendModeIsNotSpecified_defaultValueSet() {
         let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
-        assert_eq!("Rgb", result.value_of("color_mode").unwrap_or_default());
+        assert_eq!("Normal", result.value_of("blend_mode").unwrap_or_default());
     }
 
     #[test]
-    fn mode_modeIsSpecified_validationPassed() {
+    fn blendMode_unknownBlendModeGiven_validationFailed() {
         let result = get_app().try_get_matches_from(vec![
             "franklin-cli",
             "--image",
             "PATH",
-            "--mode",
-            "Grayscale",
+            "--blend-mode",
+            "Overlay",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn symmetry_rotationalSymmetryGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--symmetry",
+            "Rotational:5",
         ]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
         assert_eq!(
-            "Grayscale",
-            result.value_of("color_mode").unwrap_or_default()
+            "Rotational:5",
+            result.value_of("symmetry").unwrap_or_default()
         );
     }
 
     #[test]
-    fn mode_valueDoesNotExistInPossibleValues_validationFailed() {
+    fn symmetry_unknownSymmetryGiven_validationFailed() {
         let result = get_app().try_get_matches_from(vec![
             "franklin-cli",
             "--image",
             "PATH",
-            "--mode",
-            "FOO",
+            "--symmetry",
+            "Diagonal",
         ]);
 
         assert!(result.is_err());
     }
 
     #[test]
-    fn mutator_mutatorIsNotSpecified_defaultValueSet() {
+    fn symmetry_tooManyRotationalCopiesGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--symmetry",
+            "Rotational:1000000",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn seed_validSeedGiven_validationPassed() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "--seed", "42"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("42", result.value_of("seed").unwrap_or_default());
+    }
+
+    #[test]
+    fn seed_negativeSeedGiven_validationFailed() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "--seed", "-1"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn alpha_alphaIsNotSpecified_defaultValueSet() {
         let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
-        assert_eq!("Rectangle", result.value_of("mutator").unwrap_or_default());
+        assert_eq!("255", result.value_of("alpha").unwrap_or_default());
     }
 
     #[test]
-    fn mutator_mutatorIsSpecified_validationPassed() {
+    fn alpha_singleValueGiven_validationPassed() {
         let result = get_app().try_get_matches_from(vec![
             "franklin-cli",
             "--image",
             "PATH",
-            "--mutator",
-            "Rectangle",
+            "--alpha",
+            "128",
         ]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
-        assert_eq!("Rectangle", result.value_of("mutator").unwrap_or_default());
+        assert_eq!("128", result.value_of("alpha").unwrap_or_default());
     }
 
     #[test]
-    fn mutator_shortNameGiven_validationPassed() {
+    fn alpha_rangeGiven_validationPassed() {
         let result = get_app().try_get_matches_from(vec![
             "franklin-cli",
             "--image",
             "PATH",
-            "-m",
-            "Triangle",
+            "--alpha",
+            "64-192",
         ]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
-        assert_eq!("Triangle", result.value_of("mutator").unwrap_or_default());
+        assert_eq!("64-192", result.value_of("alpha").unwrap_or_default());
     }
 
     #[test]
-    fn mutator_valueDoesNotExistInPossibleValues_validationFailed() {
-        let result =
-            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "FOO"]);
+    fn alpha_valueGreaterThan255_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--alpha",
+            "256",
+        ]);
 
         assert!(result.is_err());
     }
 
     #[test]
-    fn mutator_circlePassedAsValue_validationPassed() {
-        let result =
-            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-m", "Circle"]);
+    fn alpha_lowerBoundGreaterThanUpperBound_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--alpha",
+            "192-64",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn antiAliasing_antiAliasingIsNotSpecified_antiAliasingIsNotPresent() {
+        let result = get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH"]);
 
         assert!(result.is_ok());
         let result = result.unwrap();
-        assert_eq!("Circle", result.value_of("mutator").unwrap_or_default());
+        assert!(!result.is_present("anti_aliasing"));
+    }
+
+    #[test]
+    fn antiAliasing_antiAliasingGiven_antiAliasingIsPresent() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "--anti-aliasing",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert!(result.is_present("anti_aliasing"));
     }
 
     #[test]
@@ -496,6 +2138,86 @@ mod test {
         );
     }
 
+    #[test]
+    fn crossover_uniformCrossoverWithProbabilityGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-c",
+            "Uniform",
+            "--crossover-probability",
+            "0.3",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Uniform", result.value_of("crossover").unwrap_or_default());
+        assert_eq!(
+            "0.3",
+            result.value_of("crossover_probability").unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn crossoverProbability_outOfRangeGiven_validationFailed() {
+        for probability in ["-0.5", "1.5", "abc"] {
+            let result = get_app().try_get_matches_from(vec![
+                "franklin-cli",
+                "--image",
+                "PATH",
+                "--crossover-probability",
+                probability,
+            ]);
+
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn maskKind_maskCrossoverGiven_defaultValueSet() {
+        let result =
+            get_app().try_get_matches_from(vec!["franklin-cli", "--image", "PATH", "-c", "Mask"]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("Mask", result.value_of("crossover").unwrap_or_default());
+        assert_eq!("Noise", result.value_of("mask_kind").unwrap_or_default());
+    }
+
+    #[test]
+    fn tileSize_tileCrossoverWithTileSizeGiven_validationPassed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-c",
+            "Tile",
+            "--tile-size",
+            "16",
+        ]);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!("16", result.value_of("tile_size").unwrap_or_default());
+        assert_eq!("Grid", result.value_of("tile_layout").unwrap_or_default());
+    }
+
+    #[test]
+    fn tileSize_zeroGiven_validationFailed() {
+        let result = get_app().try_get_matches_from(vec![
+            "franklin-cli",
+            "--image",
+            "PATH",
+            "-c",
+            "Tile",
+            "--tile-size",
+            "0",
+        ]);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn crossover_valueDoesNotExistInPossibleValues_validationFailed() {
         let result =
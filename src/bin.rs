@@ -33,6 +33,28 @@
 //! franklin-cli --image /path/to/source/image.png --fitness AbsoluteDistance --output-dir /output --save-every 50
 //! ```
 //!
+//! ```
+//! // Runs the simulation mutating half of the specimens with Rectangles, and the rest with Triangles and Circles.
+//! franklin-cli --image /path/to/source/image.png --mutator Rectangle:0.5,Triangle:0.3,Circle:0.2
+//! ```
+//!
+//! ```
+//! // Runs the simulation learning which of Rectangles, Triangles and Circles produce better specimens, and using them
+//! // more often.
+//! franklin-cli --image /path/to/source/image.png --mutator Rectangle,Triangle,Circle --adaptive-mutators
+//! ```
+//!
+//! ```
+//! // Runs the simulation drawing Ellipses repeated 6 times around the center of the image, like in a mandala.
+//! franklin-cli --image /path/to/source/image.png --mutator Ellipse --symmetry Rotational:6
+//! ```
+//!
+//! ```
+//! // Runs the simulation starting with shapes spanning up to the whole image, halving their maximal size every 500
+//! // generations, down to a tenth of the image.
+//! franklin-cli --image /path/to/source/image.png --size-schedule Generations --size-decay 500 --min-size 0.1
+//! ```
+//!
 //! To see all supported command line arguments run
 //! ```
 //! franklin-cli --help
@@ -43,11 +65,19 @@ use anyhow::{Error, Result};
 use franklin::{
     crossover::{
         ArithmeticAverageCrossover, CrossoverFunction, EqualHalfsCrossover,
-        LeftOrRightCloneCrossover,
+        LeftOrRightCloneCrossover, MaskCrossover, MaskKind, TileCrossover, TileLayout,
+        UniformCrossover,
     },
     fitness::{AbsoluteDistance, FitnessFunction, SquareDistance},
-    mutators::{CircleMutator, Mutator, RectangleMutator, TriangleMutator},
-    ArgParser, ColorMode, DisplayCondition, EnvironmentBuilder, ImageReader, SaveCondition,
+    mutators::{
+        Alpha, BezierDegree, BezierMutator, BlendMode, BlurKind, BlurMutator, CircleMutator,
+        ColorStrategy, CompositeMutator, EllipseMutator, Fill, GaussianMutator, HalftoneMutator,
+        Mutator, NoiseMutator, Placement, PolygonKind, PolygonMutator, RectangleMutator,
+        ShapeStyle, SizeSchedule, StampMutator, StrokeMutator, SymmetricMutator, Symmetry,
+        TriangleMutator,
+    },
+    ArgParser, ColorMode, DisplayCondition, EnvironmentBuilder, Image, ImageReader, Palette,
+    PaletteReader, SaveCondition,
 };
 
 #[doc(hidden)]
@@ -60,15 +90,227 @@ fn get_color_mode_from_name(name: &str) -> Result<ColorMode> {
 }
 
 #[doc(hidden)]
-fn get_mutator_from_name(name: &str) -> Result<Box<dyn Mutator + Send + Sync + 'static>> {
+fn get_blend_mode_from_name(name: &str) -> Result<BlendMode> {
+    match name {
+        "Normal" => Ok(BlendMode::Normal),
+        "Multiply" => Ok(BlendMode::Multiply),
+        "Screen" => Ok(BlendMode::Screen),
+        "Add" => Ok(BlendMode::Add),
+        "Subtract" => Ok(BlendMode::Subtract),
+        "Difference" => Ok(BlendMode::Difference),
+        "Random" => Ok(BlendMode::Random),
+        _ => Err(Error::msg("Unknown blend mode.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_fill_from_name(name: &str, stops: usize) -> Result<Fill> {
+    match name {
+        "Flat" => Ok(Fill::Flat),
+        "LinearGradient" => Ok(Fill::LinearGradient { stops }),
+        "RadialGradient" => Ok(Fill::RadialGradient { stops }),
+        _ => Err(Error::msg("Unknown fill.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_color_strategy_from_name(name: &str) -> Result<ColorStrategy> {
+    match name {
+        "Random" => Ok(ColorStrategy::Random),
+        "Mean" => Ok(ColorStrategy::Mean),
+        "LeastSquares" => Ok(ColorStrategy::LeastSquares),
+        _ => Err(Error::msg("Unknown color strategy.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_placement_from_name(name: &str) -> Result<Placement> {
+    match name {
+        "Uniform" => Ok(Placement::Uniform),
+        "ErrorGuided" => Ok(Placement::ErrorGuided),
+        _ => Err(Error::msg("Unknown placement.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_size_schedule_from_name(name: &str, args: &ArgParser) -> Result<SizeSchedule> {
+    let initial = args.get_value_t::<f64>("max_size")?;
+    let minimum = args.get_value_t::<f64>("min_size")?;
+    let decay = args.get_value_t::<u32>("size_decay")?;
+
+    match name {
+        "Constant" => Ok(SizeSchedule::Constant { size: initial }),
+        "Generations" => Ok(SizeSchedule::Generations {
+            initial,
+            minimum,
+            half_life: decay,
+        }),
+        "Plateau" => Ok(SizeSchedule::Plateau {
+            initial,
+            minimum,
+            patience: decay,
+        }),
+        _ => Err(Error::msg("Unknown size schedule.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_polygon_kind_from_name(name: &str) -> Result<PolygonKind> {
+    match name {
+        "Convex" => Ok(PolygonKind::Convex),
+        "Star" => Ok(PolygonKind::Star),
+        "Free" => Ok(PolygonKind::Free),
+        _ => Err(Error::msg("Unknown polygon kind.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_polygon_mutator(args: &ArgParser, style: ShapeStyle) -> Result<PolygonMutator> {
+    let mut mutator = PolygonMutator::new(style);
+
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    mutator.set_kind(get_polygon_kind_from_name(
+        args.get_value("polygon_kind").unwrap(),
+    )?);
+
+    let (min_vertices, max_vertices) = args.get_range("polygon_vertices")?;
+    mutator.set_vertex_count(min_vertices, max_vertices)?;
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_bezier_degree_from_name(name: &str) -> Result<BezierDegree> {
+    match name {
+        "Quadratic" => Ok(BezierDegree::Quadratic),
+        "Cubic" => Ok(BezierDegree::Cubic),
+        _ => Err(Error::msg("Unknown curve degree.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_bezier_mutator(args: &ArgParser, style: ShapeStyle) -> Result<BezierMutator> {
+    let mut mutator = BezierMutator::new(style);
+
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    mutator.set_degree(get_bezier_degree_from_name(
+        args.get_value("bezier_degree").unwrap(),
+    )?);
+    mutator.set_filled(args.is_present("bezier_filled"));
+
+    let (min_width, max_width) = args.get_range("stroke_width")?;
+    mutator.set_width(min_width, max_width)?;
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_stroke_mutator(args: &ArgParser, style: ShapeStyle, image: &Image) -> Result<StrokeMutator> {
+    let mut mutator = StrokeMutator::new(style);
+
+    let (min_width, max_width) = args.get_range("stroke_width")?;
+    mutator.set_width(min_width, max_width)?;
+
+    let (min_segments, max_segments) = args.get_range("stroke_segments")?;
+    mutator.set_segment_count(min_segments, max_segments)?;
+
+    if args.is_present("stroke_follow_edges") {
+        mutator.set_guide(image);
+    }
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_blur_kind_from_name(name: &str) -> Result<BlurKind> {
     match name {
-        "Rectangle" => Ok(Box::<RectangleMutator>::default()),
-        "Triangle" => Ok(Box::<TriangleMutator>::default()),
-        "Circle" => Ok(Box::<CircleMutator>::default()),
-        _ => Err(Error::msg("Unknown mutator.")),
+        "Box" => Ok(BlurKind::Box),
+        "Gaussian" => Ok(BlurKind::Gaussian),
+        _ => Err(Error::msg("Unknown blur kind.")),
     }
 }
 
+#[doc(hidden)]
+fn get_noise_mutator(args: &ArgParser, style: &ShapeStyle) -> Result<NoiseMutator> {
+    let mut mutator = NoiseMutator::default();
+
+    mutator.set_strength(args.get_value_t::<f64>("noise_strength")?)?;
+    mutator.set_size_schedule(style.get_size_schedule());
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_blur_mutator(args: &ArgParser, style: &ShapeStyle) -> Result<BlurMutator> {
+    let mut mutator = BlurMutator::default();
+
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    mutator.set_kind(get_blur_kind_from_name(
+        args.get_value("blur_kind").unwrap(),
+    )?);
+    mutator.set_radius(args.get_value_t::<usize>("blur_radius")?)?;
+    mutator.set_size_schedule(style.get_size_schedule());
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_halftone_mutator(args: &ArgParser, style: ShapeStyle) -> Result<HalftoneMutator> {
+    let mut mutator = HalftoneMutator::new(style);
+
+    mutator.set_cell_size(args.get_value_t::<usize>("halftone_cell_size")?)?;
+    mutator.set_jitter(args.get_value_t::<f64>("halftone_jitter")?)?;
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_stamp_mutator(args: &ArgParser, style: ShapeStyle) -> Result<StampMutator> {
+    let path = args
+        .get_value("brushes")
+        .ok_or_else(|| Error::msg("The Stamp mutator requires a directory with brushes."))?;
+    let mut mutator = StampMutator::new(style, ImageReader::load_brushes(path)?)?;
+
+    mutator.set_tinted(!args.is_present("stamp_keep_colors"));
+
+    Ok(mutator)
+}
+
+#[doc(hidden)]
+fn get_mutator_from_name(
+    name: &str,
+    args: &ArgParser,
+    style: ShapeStyle,
+    image: &Image,
+) -> Result<Box<dyn Mutator + Send + Sync + 'static>> {
+    let mutator: Box<dyn Mutator + Send + Sync + 'static> = match name {
+        "Rectangle" => Box::new(RectangleMutator::new(style)),
+        "Triangle" => Box::new(TriangleMutator::new(style)),
+        "Circle" => Box::new(CircleMutator::new(style)),
+        "Ellipse" => Box::new(EllipseMutator::new(style)),
+        "Polygon" => Box::new(get_polygon_mutator(args, style)?),
+        "Stroke" => Box::new(get_stroke_mutator(args, style, image)?),
+        "Bezier" => Box::new(get_bezier_mutator(args, style)?),
+        "Gaussian" => {
+            let mut mutator = GaussianMutator::new(style);
+            mutator.set_anisotropic(args.is_present("gaussian_anisotropic"));
+            Box::new(mutator)
+        }
+        "Noise" => Box::new(get_noise_mutator(args, &style)?),
+        "Blur" => Box::new(get_blur_mutator(args, &style)?),
+        "Stamp" => Box::new(get_stamp_mutator(args, style)?),
+        "Halftone" => Box::new(get_halftone_mutator(args, style)?),
+        _ => return Err(Error::msg("Unknown mutator.")),
+    };
+
+    if args.is_present("symmetry") {
+        let symmetry = args.get_value_t::<Symmetry>("symmetry")?;
+        return Ok(Box::new(SymmetricMutator::new(mutator, symmetry)));
+    }
+
+    Ok(mutator)
+}
+
 #[doc(hidden)]
 fn get_fitness_from_name(name: &str) -> Result<Box<dyn FitnessFunction + Send + Sync + 'static>> {
     match name {
@@ -79,15 +321,90 @@ fn get_fitness_from_name(name: &str) -> Result<Box<dyn FitnessFunction + Send +
 }
 
 #[doc(hidden)]
-fn get_crossover_from_name(name: &str) -> Result<Box<dyn CrossoverFunction + Send + 'static>> {
+fn get_mask_kind_from_name(name: &str) -> Result<MaskKind> {
+    match name {
+        "Noise" => Ok(MaskKind::Noise),
+        "Shape" => Ok(MaskKind::Shape),
+        _ => Err(Error::msg("Unknown mask kind.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_tile_layout_from_name(name: &str) -> Result<TileLayout> {
+    match name {
+        "Grid" => Ok(TileLayout::Grid),
+        "Regions" => Ok(TileLayout::Regions),
+        _ => Err(Error::msg("Unknown tile layout.")),
+    }
+}
+
+#[doc(hidden)]
+fn get_tile_crossover(args: &ArgParser) -> Result<TileCrossover> {
+    let mut crossover = TileCrossover::default();
+
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    crossover.set_layout(get_tile_layout_from_name(
+        args.get_value("tile_layout").unwrap(),
+    )?);
+    if args.is_present("tile_size") {
+        crossover.set_tile_size(args.get_value_t::<usize>("tile_size")?)?;
+    }
+
+    Ok(crossover)
+}
+
+#[doc(hidden)]
+fn get_crossover_from_name(
+    name: &str,
+    args: &ArgParser,
+) -> Result<Box<dyn CrossoverFunction + Send + 'static>> {
     match name {
         "LeftOrRight" => Ok(Box::<LeftOrRightCloneCrossover>::default()),
         "EqualHalfs" => Ok(Box::<EqualHalfsCrossover>::default()),
         "ArithmeticAverage" => Ok(Box::<ArithmeticAverageCrossover>::default()),
+        "Uniform" => {
+            let mut crossover = UniformCrossover::default();
+            crossover.set_probability(args.get_value_t::<f64>("crossover_probability")?)?;
+            Ok(Box::new(crossover))
+        }
+        "Mask" => {
+            let mut crossover = MaskCrossover::default();
+            // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+            crossover.set_kind(get_mask_kind_from_name(
+                args.get_value("mask_kind").unwrap(),
+            )?);
+            Ok(Box::new(crossover))
+        }
+        "Tile" => Ok(Box::new(get_tile_crossover(args)?)),
         _ => Err(Error::msg("Unknown crossover function.")),
     }
 }
 
+#[doc(hidden)]
+fn get_mutator(
+    args: &ArgParser,
+    style: ShapeStyle,
+    image: &Image,
+) -> Result<Box<dyn Mutator + Send + Sync + 'static>> {
+    let mut mutators = args.get_weighted_values("mutator")?;
+
+    if mutators.len() == 1 {
+        // Safety: it's safe to unwrap because the list has exactly one element.
+        let (name, _) = mutators.pop().unwrap();
+        return get_mutator_from_name(&name, args, style, image);
+    }
+
+    let mut composite_mutator = CompositeMutator::default();
+    for (name, weight) in mutators {
+        composite_mutator.add_mutator(
+            get_mutator_from_name(&name, args, style.clone(), image)?,
+            weight,
+        )?;
+    }
+
+    Ok(Box::new(composite_mutator))
+}
+
 /// The entry point of the program.
 ///
 /// # Usage
@@ -104,15 +421,62 @@ fn main() -> Result<()> {
     let image = ImageReader::load(args.get_value("image").unwrap())?;
 
     let mut environment_builder = EnvironmentBuilder::default();
-    environment_builder.set_image(image);
 
     // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
     environment_builder.set_color_mode(get_color_mode_from_name(
         args.get_value("color_mode").unwrap(),
     )?);
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    environment_builder.set_blend_mode(get_blend_mode_from_name(
+        args.get_value("blend_mode").unwrap(),
+    )?);
 
+    let mut style = ShapeStyle::default();
+    style.set_alpha(args.get_value_t::<Alpha>("alpha")?);
+    style.set_anti_aliasing(args.is_present("anti_aliasing"));
     // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
-    environment_builder.set_mutator(get_mutator_from_name(args.get_value("mutator").unwrap())?);
+    style.set_fill(get_fill_from_name(
+        args.get_value("fill").unwrap(),
+        args.get_value_t::<usize>("gradient_stops")?,
+    )?);
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    style.set_color_strategy(get_color_strategy_from_name(
+        args.get_value("color_strategy").unwrap(),
+    )?);
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    style.set_size_schedule(get_size_schedule_from_name(
+        args.get_value("size_schedule").unwrap(),
+        &args,
+    )?);
+    // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
+    style.set_placement(get_placement_from_name(
+        args.get_value("placement").unwrap(),
+    )?);
+
+    if args.is_present("outline") {
+        let (min_width, max_width) = args.get_range("outline")?;
+        style.set_outline(min_width, max_width)?;
+    }
+
+    if args.is_present("adaptive_mutators") {
+        for (name, weight) in args.get_weighted_values("mutator")? {
+            let mutator = get_mutator_from_name(&name, &args, style.clone(), &image)?;
+            environment_builder.add_adaptive_mutator(&name, mutator, weight)?;
+        }
+    } else {
+        environment_builder.set_mutator(get_mutator(&args, style, &image)?);
+    }
+
+    if let Some(path) = args.get_value("palette") {
+        environment_builder.set_palette(PaletteReader::load(path)?);
+    } else if let Ok(size) = args.get_value_t::<usize>("palette_size") {
+        // If "palette_size" has been passed we should always end up here. This argument has a validator which checks
+        // if the value can be parsed to a usize.
+        let seed = args.get_value_t::<u64>("seed").ok();
+        environment_builder.set_palette(Palette::extract(&image, size, seed)?);
+    }
+
+    environment_builder.set_image(image);
 
     // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
     environment_builder
@@ -121,6 +485,7 @@ fn main() -> Result<()> {
     // Safety: it's safe to unwrap because this argument has a default value, i.e. it cannot be empty or None.
     environment_builder.set_crossover_function(get_crossover_from_name(
         args.get_value("crossover").unwrap(),
+        &args,
     )?);
 
     // Safety: it's safe to unwrap because this argument has a validator which checks if the value can be parsed to a
@@ -133,6 +498,12 @@ fn main() -> Result<()> {
     let threads: usize = args.get_value_t("threads")?;
     environment_builder.set_threads(threads);
 
+    if let Ok(seed) = args.get_value_t::<u64>("seed") {
+        // If "seed" has been passed we should always end up here. This argument has a validator which checks if the
+        // value can be parsed to a u64.
+        environment_builder.set_seed(seed);
+    }
+
     if args.is_present("display") {
         if args.is_present("display_all") {
             environment_builder.set_display_condition(DisplayCondition::All);
@@ -3,15 +3,24 @@ use crate::models::Image;
 /// This trait defines functionality used to breed two specimens.
 ///
 /// You can use this trait to implement your own crossover function, but take a look at already implemented crossover
-/// functions: ([ArithmeticAverageCrossover], [EqualHalfsCrossover], [LeftOrRightCloneCrossover]).
+/// functions: ([ArithmeticAverageCrossover], [EqualHalfsCrossover], [LeftOrRightCloneCrossover], [UniformCrossover],
+/// [MaskCrossover], [TileCrossover]).
 ///
 /// [ArithmeticAverageCrossover]: crate::crossover::ArithmeticAverageCrossover
 /// [EqualHalfsCrossover]: crate::crossover::EqualHalfsCrossover
 /// [LeftOrRightCloneCrossover]: crate::crossover::LeftOrRightCloneCrossover
+/// [UniformCrossover]: crate::crossover::UniformCrossover
+/// [MaskCrossover]: crate::crossover::MaskCrossover
+/// [TileCrossover]: crate::crossover::TileCrossover
 pub trait CrossoverFunction {
     /// This method create a new image which is a product of breeding two images.
     ///
     /// The product does not necessarily has to be a product of crossing two images. Some implementation may ignore
     /// either image or both of them.
     fn crossover(&mut self, first_image: &Image, second_image: &Image) -> Image;
+
+    /// This method reseeds the random number generator of the crossover function, if it has one.
+    ///
+    /// The environment calls it when the simulation is seeded, so that random crossover functions are deterministic.
+    fn set_seed(&mut self, _seed: u64) {}
 }
@@ -0,0 +1,158 @@
+use std::f64::consts::PI;
+
+use crate::{
+    geometry::{Ellipse, Shape},
+    models::Image,
+    util::Random,
+};
+
+use super::{select_pixels, CrossoverFunction, MaskKind};
+
+/// Number of cells of the noise grid along each side of the image.
+const NOISE_CELLS: usize = 4;
+
+/// This crossover function breeds specimens by drawing a random smooth mask, which decides for every pixel whether it
+/// comes from `first_image` or `second_image`.
+///
+/// Unlike pixel-wise crossovers, masks keep contiguous regions of both parents intact.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::crossover::{MaskCrossover, MaskKind};
+///
+/// let mut crossover = MaskCrossover::default();
+/// crossover.set_kind(MaskKind::Shape);
+/// ```
+#[derive(Debug, Default)]
+pub struct MaskCrossover {
+    kind: MaskKind,
+    random: Random,
+}
+
+impl MaskCrossover {
+    /// Sets how masks are drawn.
+    ///
+    /// This property is optional. The default value is: [MaskKind::Noise].
+    pub fn set_kind(&mut self, kind: MaskKind) {
+        self.kind = kind;
+    }
+
+    /// Returns a mask of the given size, where `true` means that the pixel comes from the second parent.
+    fn get_random_mask(&mut self, width: usize, height: usize) -> Vec<bool> {
+        match self.kind {
+            MaskKind::Noise => self.get_noise_mask(width, height),
+            MaskKind::Shape => self.get_shape_mask(width, height),
+        }
+    }
+
+    fn get_noise_mask(&mut self, width: usize, height: usize) -> Vec<bool> {
+        let lattice = (0..(NOISE_CELLS + 1) * (NOISE_CELLS + 1))
+            .map(|_| self.random.get_random(0f64, 1f64))
+            .collect::<Vec<f64>>();
+        let value = |column: usize, row: usize| lattice[row * (NOISE_CELLS + 1) + column];
+
+        let mut mask = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                // Bilinear interpolation between the four grid points around the pixel.
+                let u = (x as f64 + 0.5) / width as f64 * NOISE_CELLS as f64;
+                let v = (y as f64 + 0.5) / height as f64 * NOISE_CELLS as f64;
+                let (column, row) = (u as usize, v as usize);
+                let (u, v) = (u - column as f64, v - row as f64);
+
+                let top = value(column, row) * (1f64 - u) + value(column + 1, row) * u;
+                let bottom = value(column, row + 1) * (1f64 - u) + value(column + 1, row + 1) * u;
+
+                mask.push(top * (1f64 - v) + bottom * v >= 0.5);
+            }
+        }
+
+        mask
+    }
+
+    fn get_shape_mask(&mut self, width: usize, height: usize) -> Vec<bool> {
+        let max_semi_axis = (width.max(height) as f64 / 2f64).max(1f64);
+        let semi_major = self.random.get_random(1f64, max_semi_axis + 1f64);
+
+        let ellipse = Ellipse {
+            x: self.random.get_random(0f64, width as f64),
+            y: self.random.get_random(0f64, height as f64),
+            semi_major,
+            semi_minor: self.random.get_random(1f64, semi_major + 1f64),
+            angle: self.random.get_random(0f64, PI),
+        };
+
+        (0..width * height)
+            .map(|index| {
+                ellipse.contains((index % width) as f64 + 0.5, (index / width) as f64 + 0.5)
+            })
+            .collect()
+    }
+}
+
+impl CrossoverFunction for MaskCrossover {
+    fn crossover(&mut self, first_image: &Image, second_image: &Image) -> Image {
+        let mask = self.get_random_mask(first_image.width(), first_image.height());
+
+        select_pixels(first_image, second_image, mask)
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.random = Random::from_seed(seed);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::models::Pixel;
+
+    fn count_switches(mask: &[bool], width: usize) -> usize {
+        mask.chunks(width)
+            .map(|row| row.windows(2).filter(|pair| pair[0] != pair[1]).count())
+            .sum()
+    }
+
+    #[test]
+    fn getRandomMask_noiseMask_maskSmooth() {
+        let mut crossover = MaskCrossover::default();
+
+        for _ in 0..20 {
+            let mask = crossover.get_random_mask(40, 30);
+
+            // Interpolated noise crosses the threshold only a few times per row.
+            assert!(count_switches(&mask, 40) <= 30 * NOISE_CELLS);
+        }
+    }
+
+    #[test]
+    fn getRandomMask_shapeMask_maskSmooth() {
+        let mut crossover = MaskCrossover::default();
+        crossover.set_kind(MaskKind::Shape);
+
+        for _ in 0..20 {
+            let mask = crossover.get_random_mask(40, 30);
+
+            // Every row crosses an ellipse at most twice.
+            assert!(count_switches(&mask, 40) <= 30 * 2);
+        }
+    }
+
+    #[test]
+    fn crossover_anyMask_everyPixelFromOneOfParents() {
+        let mut crossover = MaskCrossover::default();
+        let first = Image::blank(20, 30, &Pixel::grayscale(0));
+        let second = Image::blank(20, 30, &Pixel::white());
+
+        let child = crossover.crossover(&first, &second);
+
+        assert_eq!(20, child.height());
+        assert_eq!(30, child.width());
+        assert!(child
+            .pixels()
+            .iter()
+            .all(|pixel| *pixel == Pixel::grayscale(0) || *pixel == Pixel::white()));
+    }
+}
@@ -0,0 +1,12 @@
+/// This enum specifies how masks of [MaskCrossover] are drawn.
+///
+/// [MaskCrossover]: crate::crossover::MaskCrossover
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaskKind {
+    /// Random noise, smoothly interpolated between points of a coarse grid, splits the image into blobs.
+    #[default]
+    Noise,
+
+    /// A random ellipse, inside of which pixels come from the second parent.
+    Shape,
+}
@@ -9,3 +9,21 @@ pub use equal_halfs_crossover::EqualHalfsCrossover;
 
 mod left_or_right_clone_crossover;
 pub use left_or_right_clone_crossover::LeftOrRightCloneCrossover;
+
+mod uniform_crossover;
+pub use uniform_crossover::UniformCrossover;
+
+mod mask_kind;
+pub use mask_kind::MaskKind;
+
+mod mask_crossover;
+pub use mask_crossover::MaskCrossover;
+
+mod tile_layout;
+pub use tile_layout::TileLayout;
+
+mod tile_crossover;
+pub use tile_crossover::TileCrossover;
+
+mod pixel_selection;
+pub(crate) use pixel_selection::select_pixels;
@@ -0,0 +1,39 @@
+use crate::models::{Image, Pixel};
+
+/// Returns a child whose pixels are taken from `second_image` where `mask` is `true`, and from `first_image` elsewhere.
+pub(crate) fn select_pixels(first_image: &Image, second_image: &Image, mask: Vec<bool>) -> Image {
+    let pixels = first_image
+        .pixels()
+        .iter()
+        .zip(second_image.pixels().iter())
+        .zip(mask)
+        .map(|((first, second), from_second)| {
+            if from_second {
+                second.clone()
+            } else {
+                first.clone()
+            }
+        })
+        .collect::<Vec<Pixel>>();
+
+    Image::new(first_image.height(), first_image.width(), pixels)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn selectPixels_anyMask_maskedPixelsTakenFromSecondImage() {
+        let first = Image::blank(1, 3, &Pixel::grayscale(0));
+        let second = Image::blank(1, 3, &Pixel::white());
+
+        let child = select_pixels(&first, &second, vec![true, false, true]);
+
+        assert_eq!(
+            &vec![Pixel::white(), Pixel::grayscale(0), Pixel::white()],
+            child.pixels()
+        );
+    }
+}
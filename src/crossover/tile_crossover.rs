@@ -0,0 +1,173 @@
+use anyhow::{Error, Result};
+
+use crate::{models::Image, util::Random};
+
+use super::{select_pixels, CrossoverFunction, TileLayout};
+
+/// The highest number of cuts along each axis of images split into [TileLayout::Regions].
+const MAX_CUTS: usize = 4;
+
+/// This crossover function breeds specimens by splitting the image into rectangular tiles and copying every tile from
+/// either `first_image` or `second_image`, picked at random.
+///
+/// Unlike [EqualHalfsCrossover], tiles mix features of both parents along both axes.
+///
+/// [EqualHalfsCrossover]: crate::crossover::EqualHalfsCrossover
+///
+/// # Examples
+///
+/// ```
+/// use franklin::crossover::TileCrossover;
+///
+/// let mut crossover = TileCrossover::default();
+/// crossover.set_tile_size(16).unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct TileCrossover {
+    layout: TileLayout,
+    tile_size: Option<usize>,
+    random: Random,
+}
+
+impl TileCrossover {
+    /// Sets how images are split into tiles.
+    ///
+    /// This property is optional. The default value is: [TileLayout::Grid].
+    pub fn set_layout(&mut self, layout: TileLayout) {
+        self.layout = layout;
+    }
+
+    /// Sets the length in pixels of the side of tiles of [TileLayout::Grid].
+    ///
+    /// This property is optional. By default every crossover picks a random size, up to half of the shorter side of
+    /// the image.
+    ///
+    /// Returns an error if `tile_size` is 0.
+    pub fn set_tile_size(&mut self, tile_size: usize) -> Result<()> {
+        if tile_size == 0 {
+            return Err(Error::msg("Size of a tile must be a positive integer."));
+        }
+
+        self.tile_size = Some(tile_size);
+
+        Ok(())
+    }
+
+    /// Returns sorted positions along an axis of the given length, where tiles start, including 0.
+    fn get_tile_starts(&mut self, length: usize, tile_size: usize) -> Vec<usize> {
+        match self.layout {
+            TileLayout::Grid => (0..length).step_by(tile_size).collect(),
+            TileLayout::Regions => {
+                let cuts = self.random.get_random(1, MAX_CUTS + 1);
+
+                let mut starts = (0..cuts)
+                    .map(|_| self.random.get_random(0, length))
+                    .collect::<Vec<usize>>();
+                starts.push(0);
+                starts.sort_unstable();
+                starts.dedup();
+
+                starts
+            }
+        }
+    }
+
+    /// Returns the tile of every pixel of the image, where `true` means that the pixel comes from the second parent.
+    fn get_random_tiles(&mut self, width: usize, height: usize) -> Vec<bool> {
+        let tile_size = self.tile_size.unwrap_or_else(|| {
+            let max_tile_size = (width.min(height) / 2).max(2);
+            self.random.get_random(2, max_tile_size + 1)
+        });
+
+        let columns = self.get_tile_starts(width, tile_size);
+        let rows = self.get_tile_starts(height, tile_size);
+        let parents = (0..columns.len() * rows.len())
+            .map(|_| self.random.get_random(0f64, 1f64) < 0.5)
+            .collect::<Vec<bool>>();
+
+        let mut tiles = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let row = rows.partition_point(|&start| start <= y) - 1;
+            for x in 0..width {
+                let column = columns.partition_point(|&start| start <= x) - 1;
+                tiles.push(parents[row * columns.len() + column]);
+            }
+        }
+
+        tiles
+    }
+}
+
+impl CrossoverFunction for TileCrossover {
+    fn crossover(&mut self, first_image: &Image, second_image: &Image) -> Image {
+        let tiles = self.get_random_tiles(first_image.width(), first_image.height());
+
+        select_pixels(first_image, second_image, tiles)
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.random = Random::from_seed(seed);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn setTileSize_zeroGiven_errorReturned() {
+        let mut crossover = TileCrossover::default();
+
+        assert!(crossover.set_tile_size(0).is_err());
+    }
+
+    #[test]
+    fn getRandomTiles_gridLayout_tilesUniform() {
+        let mut crossover = TileCrossover::default();
+        crossover.set_tile_size(4).unwrap();
+
+        for _ in 0..20 {
+            let tiles = crossover.get_random_tiles(10, 7);
+
+            for y in 0..7 {
+                for x in 0..10 {
+                    let corner = (y / 4 * 4) * 10 + x / 4 * 4;
+                    assert_eq!(tiles[corner], tiles[y * 10 + x]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn getRandomTiles_manyCrossovers_bothParentsUsedAlongBothAxes() {
+        let mut crossover = TileCrossover::default();
+        crossover.set_tile_size(5).unwrap();
+
+        let (mut horizontal, mut vertical) = (false, false);
+        for _ in 0..100 {
+            let tiles = crossover.get_random_tiles(10, 10);
+            horizontal |= tiles[0] != tiles[5];
+            vertical |= tiles[0] != tiles[50];
+        }
+
+        assert!(horizontal);
+        assert!(vertical);
+    }
+
+    #[test]
+    fn getRandomTiles_regionsLayout_rowsAndColumnsOfTilesAligned() {
+        let mut crossover = TileCrossover::default();
+        crossover.set_layout(TileLayout::Regions);
+
+        for _ in 0..20 {
+            let tiles = crossover.get_random_tiles(12, 9);
+
+            // Cuts span the whole image, so every row or column changes parents at most once per cut.
+            for row in tiles.chunks(12) {
+                let switches = row.windows(2).filter(|pair| pair[0] != pair[1]).count();
+                assert!(switches <= MAX_CUTS);
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// This enum specifies how [TileCrossover] splits images into tiles.
+///
+/// [TileCrossover]: crate::crossover::TileCrossover
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TileLayout {
+    /// Square tiles of equal size, aligned to a regular grid.
+    #[default]
+    Grid,
+
+    /// Rectangles of random sizes, separated by a few cuts placed at random along both axes.
+    Regions,
+}
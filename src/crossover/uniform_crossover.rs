@@ -0,0 +1,120 @@
+use anyhow::{Error, Result};
+
+use crate::{
+    models::{Image, Pixel},
+    util::Random,
+};
+
+use super::CrossoverFunction;
+
+/// This crossover function breeds specimens by picking every pixel from either `first_image` or `second_image` at
+/// random.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::crossover::UniformCrossover;
+///
+/// let mut crossover = UniformCrossover::default();
+/// crossover.set_probability(0.7).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct UniformCrossover {
+    probability: f64,
+    random: Random,
+}
+
+impl UniformCrossover {
+    /// Sets the probability with which every pixel is picked from `first_image`.
+    ///
+    /// This property is optional. The default value is: `0.5`.
+    ///
+    /// Returns an error if `probability` is not in range `[0, 1]`.
+    pub fn set_probability(&mut self, probability: f64) -> Result<()> {
+        if !(0f64..=1f64).contains(&probability) {
+            return Err(Error::msg("Probability must be a number between 0 and 1."));
+        }
+
+        self.probability = probability;
+
+        Ok(())
+    }
+}
+
+impl Default for UniformCrossover {
+    fn default() -> Self {
+        Self {
+            probability: 0.5,
+            random: Random::default(),
+        }
+    }
+}
+
+impl CrossoverFunction for UniformCrossover {
+    fn crossover(&mut self, first_image: &Image, second_image: &Image) -> Image {
+        let pixels = first_image
+            .pixels()
+            .iter()
+            .zip(second_image.pixels().iter())
+            .map(|(first, second)| {
+                if self.random.get_random(0f64, 1f64) < self.probability {
+                    first.clone()
+                } else {
+                    second.clone()
+                }
+            })
+            .collect::<Vec<Pixel>>();
+
+        Image::new(first_image.height(), first_image.width(), pixels)
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.random = Random::from_seed(seed);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn setProbability_outOfRangeGiven_errorReturned() {
+        let mut crossover = UniformCrossover::default();
+
+        assert!(crossover.set_probability(-0.1).is_err());
+        assert!(crossover.set_probability(1.1).is_err());
+        assert!(crossover.set_probability(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn crossover_anyProbability_pixelsPickedAccordingToProbability() {
+        let mut crossover = UniformCrossover::default();
+        crossover.set_probability(0.75).unwrap();
+        let first = Image::blank(100, 40, &Pixel::grayscale(0));
+        let second = Image::blank(100, 40, &Pixel::white());
+
+        let child = crossover.crossover(&first, &second);
+
+        let from_first = child
+            .pixels()
+            .iter()
+            .filter(|pixel| **pixel == Pixel::grayscale(0))
+            .count();
+        assert!((2700..3300).contains(&from_first));
+    }
+
+    #[test]
+    fn crossover_sameSeeds_sameChildrenProduced() {
+        let first = Image::blank(10, 10, &Pixel::grayscale(0));
+        let second = Image::blank(10, 10, &Pixel::white());
+        let mut crossovers = [UniformCrossover::default(), UniformCrossover::default()];
+        crossovers
+            .iter_mut()
+            .for_each(|crossover| crossover.set_seed(3));
+
+        let children = crossovers.map(|mut crossover| crossover.crossover(&first, &second));
+
+        assert_eq!(children[0].pixels(), children[1].pixels());
+    }
+}
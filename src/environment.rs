@@ -1,14 +1,16 @@
-use std::sync::mpsc::channel;
+use std::sync::{mpsc::channel, Arc};
 
 use anyhow::Result;
-use rand::prelude::SliceRandom;
+use rand::{prelude::SliceRandom, Rng};
 use rayon::spawn;
 
 use crate::{
+    adaptive_selection::AdaptiveSelection,
     crossover::CrossoverFunction,
     display::Window,
     job_context::JobContext,
-    models::{Image, Pixel},
+    models::{ErrorMap, Image, Pixel},
+    mutators::MutationContext,
     util::Random,
     ColorMode, DisplayCondition, ImageWriter,
 };
@@ -45,21 +47,30 @@ pub struct Environment {
     generation: Vec<(Image, usize)>,
     best_from_generation_size: usize,
     current_generation_number: u32,
+    stalled_generations: u32,
 
     random: Random,
     image_writer: ImageWriter,
+    selection: Option<AdaptiveSelection>,
 }
 
 impl Environment {
     #[must_use]
     pub(crate) fn new(
         job_context: JobContext,
-        generation_size: usize,
-        crossover: Box<dyn CrossoverFunction + Send>,
+        mut crossover: Box<dyn CrossoverFunction + Send>,
         display_condition: DisplayCondition,
         image_writer: ImageWriter,
         should_save_specimen: Box<dyn Fn(u32) -> bool + Send>,
+        selection: Option<AdaptiveSelection>,
+        seed: Option<u64>,
     ) -> Self {
+        let mut random = seed.map_or_else(Random::default, Random::from_seed);
+        if seed.is_some() {
+            crossover.set_seed(random.get_random(0, u64::MAX));
+        }
+
+        let generation_size = job_context.get_configuration().get_generation_size();
         let generation = get_first_generation(
             generation_size,
             job_context.get_image().height(),
@@ -74,61 +85,115 @@ impl Environment {
             generation,
             best_from_generation_size: get_best_size(generation_size),
             current_generation_number: 0,
-            random: Random::default(),
+            stalled_generations: 0,
+            random,
             image_writer,
+            selection,
         }
     }
 
     #[must_use]
     fn mutate_generation(mut self) -> Self {
-        let mut new_generation = Vec::with_capacity(self.generation.len());
+        let generation_size = self.generation.len();
+        let previous_best = self.generation[0].1;
+        let current_generation_number = self.current_generation_number;
+        let stalled_generations = self.stalled_generations;
+        let error_map = self
+            .job_context
+            .get_configuration()
+            .get_error_guided()
+            .then(|| {
+                Arc::new(ErrorMap::new(
+                    self.job_context.get_image(),
+                    &self.generation[0].0,
+                    self.job_context.get_color_mode(),
+                ))
+            });
+
         let mut old_generation = self.generation.into_iter();
 
         // Safety: it's safe to unwrap here because generation always has fixed number of specimens and it cannot be
         // less than 3.
-        new_generation.push(old_generation.next().unwrap());
+        let best = old_generation.next().unwrap();
 
         let (tx, rx) = channel();
 
-        old_generation.for_each(|mut entry| {
+        old_generation.enumerate().for_each(|(index, mut entry)| {
             let tx = tx.clone();
             let context = self.job_context.clone();
+            let error_map = error_map.clone();
+            let mutator = match &self.selection {
+                Some(selection) => selection.pick(&mut self.random),
+                None => 0,
+            };
+            let seed = self.random.get_rng().gen::<u64>();
 
             spawn(move || {
-                match context.get_color_mode() {
-                    ColorMode::Rgb => {
-                        context.get_mutator().mutate_rgb(&mut entry.0);
-                        entry.1 = context
-                            .get_fitness()
-                            .calculate_fitness_rgb(context.get_image(), &entry.0);
-                    }
-                    ColorMode::Grayscale => {
-                        context.get_mutator().mutate_grayscale(&mut entry.0);
-                        entry.1 = context
-                            .get_fitness()
-                            .calculate_fitness_grayscale(context.get_image(), &entry.0);
-                    }
+                let mut mutation_context = MutationContext::new(context.get_configuration());
+                mutation_context.set_seed(seed);
+                mutation_context.set_generation(current_generation_number);
+                mutation_context.set_stalled_generations(stalled_generations);
+                mutation_context.set_target(context.get_image());
+                if let Some(error_map) = &error_map {
+                    mutation_context.set_error_map(error_map);
                 }
 
+                context
+                    .get_mutator(mutator)
+                    .mutate(&mut entry.0, &mut mutation_context);
+
+                entry.1 = match context.get_color_mode() {
+                    ColorMode::Rgb => context
+                        .get_fitness()
+                        .calculate_fitness_rgb(context.get_image(), &entry.0),
+                    ColorMode::Grayscale => context
+                        .get_fitness()
+                        .calculate_fitness_grayscale(context.get_image(), &entry.0),
+                };
+
                 // Safety: it's ok to unwrap here because the flow of the program guarantees that the Receiver<T> (rx)
                 // will outlive all Senders<T> (tx).
                 // See: https://doc.rust-lang.org/std/sync/mpsc/struct.Sender.html#method.send
-                tx.send(entry).unwrap();
+                tx.send((index, entry, mutator)).unwrap();
             });
         });
 
         drop(tx);
-        new_generation.extend(rx.iter());
+
+        // Specimens are put back in their original order, so that seeded simulations don't depend on the order in which
+        // threads finish their jobs.
+        let mut mutated = rx.iter().collect::<Vec<_>>();
+        mutated.sort_by_key(|(index, _, _)| *index);
+
+        // A mutation is successful if it produced a specimen better than the best one from the previous generation.
+        if let Some(selection) = &mut self.selection {
+            let outcomes = mutated
+                .iter()
+                .map(|(_, entry, mutator)| (*mutator, entry.1 < previous_best))
+                .collect::<Vec<_>>();
+
+            selection.update(&outcomes);
+        }
+
+        let mut new_generation = Vec::with_capacity(generation_size);
+        new_generation.push(best);
+        new_generation.extend(mutated.into_iter().map(|(_, entry, _)| entry));
+
         self.generation = new_generation;
 
         self
     }
 
     fn run_single_generation(mut self) -> Result<Self> {
+        let previous_best = self.generation[0].1;
         self = self.mutate_generation();
 
         // Sort
-        self.generation.sort_by(|a, b| a.1.cmp(&b.1));
+        self.generation.sort_by_key(|entry| entry.1);
+
+        if self.generation[0].1 >= previous_best {
+            self.stalled_generations += 1;
+        }
 
         // Dump worst
         let generation_size = self.generation.len();
@@ -147,10 +212,16 @@ impl Environment {
         }
 
         self.current_generation_number += 1;
-        println!(
-            "Current generation: {} ({})",
-            self.current_generation_number, self.generation[0].1
-        );
+        match &self.selection {
+            Some(selection) => println!(
+                "Current generation: {} ({}) [{}]",
+                self.current_generation_number, self.generation[0].1, selection
+            ),
+            None => println!(
+                "Current generation: {} ({})",
+                self.current_generation_number, self.generation[0].1
+            ),
+        }
 
         Ok(self)
     }
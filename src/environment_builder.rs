@@ -4,13 +4,14 @@ use anyhow::{Error, Result};
 use rayon::ThreadPoolBuilder;
 
 use crate::{
+    adaptive_selection::AdaptiveSelection,
     crossover::{CrossoverFunction, EqualHalfsCrossover},
     environment::Environment,
     fitness::{FitnessFunction, SquareDistance},
     job_context::JobContext,
-    models::Image,
-    mutators::{Mutator, RectangleMutator},
-    ColorMode, DisplayCondition, ImageWriter, SaveCondition,
+    models::{Image, Palette},
+    mutators::{BlendMode, Mutator, RectangleMutator},
+    ColorMode, DisplayCondition, ImageWriter, RunConfiguration, SaveCondition,
 };
 
 /// This builder provides an interface to set up the environment for generating images.
@@ -18,6 +19,7 @@ pub struct EnvironmentBuilder {
     image: Option<Image>,
     color_mode: ColorMode,
     mutator: Box<dyn Mutator + Send + Sync>,
+    adaptive_mutators: Vec<(String, Box<dyn Mutator + Send + Sync>, f64)>,
     fitness: Box<dyn FitnessFunction + Send + Sync>,
     crossover: Box<dyn CrossoverFunction + Send>,
     generation_size: usize,
@@ -26,6 +28,10 @@ pub struct EnvironmentBuilder {
     output_directory: String,
     filename_prefix: String,
     save_condition: SaveCondition,
+    palette: Option<Palette>,
+    seed: Option<u64>,
+    blend_mode: BlendMode,
+    error_guided: bool,
 }
 
 impl EnvironmentBuilder {
@@ -83,6 +89,48 @@ impl EnvironmentBuilder {
         self.mutator = mutator;
     }
 
+    /// Adds a mutator picked by adaptive selection.
+    ///
+    /// When at least one adaptive mutator has been added, they replace the mutator set with
+    /// [EnvironmentBuilder#set_mutator()]. Every mutation is then performed by one of them, picked with probability
+    /// proportional to how often its recent mutations produced a specimen better than the best one from the previous
+    /// generation. `weight` is the initial weight of the mutator, used until enough mutations have been performed.
+    /// A small part of the probability is always spread evenly among all mutators, so none of them is abandoned.
+    /// Current probabilities are reported along with the fitness of every generation, labeled with `name`.
+    ///
+    /// Returns an error if `weight` is not a positive number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::EnvironmentBuilder;
+    /// use franklin::mutators::{RectangleMutator, TriangleMutator};
+    ///
+    /// let mut environment_builder = EnvironmentBuilder::default();
+    ///
+    /// environment_builder
+    ///     .add_adaptive_mutator("Rectangle", Box::new(RectangleMutator::default()), 1.0)
+    ///     .unwrap();
+    /// environment_builder
+    ///     .add_adaptive_mutator("Triangle", Box::new(TriangleMutator::default()), 1.0)
+    ///     .unwrap();
+    /// ```
+    pub fn add_adaptive_mutator(
+        &mut self,
+        name: &str,
+        mutator: Box<dyn Mutator + Send + Sync>,
+        weight: f64,
+    ) -> Result<()> {
+        if !weight.is_finite() || weight <= 0f64 {
+            return Err(Error::msg("Weight of a mutator must be a positive number."));
+        }
+
+        self.adaptive_mutators
+            .push((name.to_owned(), mutator, weight));
+
+        Ok(())
+    }
+
     /// Sets the fitness function.
     ///
     /// This property is optional. The default value is: SquareDistance.
@@ -233,6 +281,104 @@ impl EnvironmentBuilder {
         self.filename_prefix = filename_prefix.to_owned();
     }
 
+    /// Sets the palette.
+    ///
+    /// When the palette is set, every pixel painted by a mutator is snapped to the closest color of the palette after
+    /// it is composited, so gradients, translucent shapes, anti-aliased edges and blend modes never produce colors
+    /// outside of it. Pixels of the initial white canvas which no mutator painted over keep their color. The palette
+    /// is saved along with the specimens as "{prefix}palette.gpl". In grayscale mode colors of the palette are
+    /// converted to shades of gray. This property is optional. By default colors are not restricted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::{EnvironmentBuilder, Palette, Pixel};
+    ///
+    /// let mut environment_builder = EnvironmentBuilder::default();
+    ///
+    /// let palette = Palette::new(vec![Pixel::new(0, 0, 0), Pixel::white()]).unwrap();
+    /// environment_builder.set_palette(palette);
+    /// ```
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = Some(palette);
+    }
+
+    /// Sets the seed of the random number generator of the simulation.
+    ///
+    /// Simulations with the same seed and settings produce the same specimens, as long as all mutators and crossover
+    /// functions draw random numbers only from generators provided by the environment. Palettes extracted with
+    /// [Palette::extract] should be given the same seed. This property is optional. By default the generator is
+    /// seeded from entropy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::EnvironmentBuilder;
+    ///
+    /// let mut environment_builder = EnvironmentBuilder::default();
+    ///
+    /// environment_builder.set_seed(42);
+    /// ```
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+    }
+
+    /// Sets the blend mode.
+    ///
+    /// The blend mode decides how colors of shapes are combined with colors of specimens underneath them. Mutators can
+    /// override it with [ShapeStyle#set_blend_mode()]. This property is optional. The default value is: Normal.
+    ///
+    /// [ShapeStyle#set_blend_mode()]: crate::mutators::ShapeStyle::set_blend_mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::EnvironmentBuilder;
+    /// use franklin::mutators::BlendMode;
+    ///
+    /// let mut environment_builder = EnvironmentBuilder::default();
+    ///
+    /// environment_builder.set_blend_mode(BlendMode::Screen);
+    /// ```
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Sets whether the error map of the best specimen is computed for every generation and passed to mutators.
+    ///
+    /// The error map is always computed when one of the mutators needs it, e.g. because its style uses
+    /// [Placement::ErrorGuided], see [Mutator#uses_error_map()]. This method makes it available to other mutators as
+    /// well. This property is optional. The default value is: `false`.
+    ///
+    /// [Placement::ErrorGuided]: crate::mutators::Placement::ErrorGuided
+    /// [Mutator#uses_error_map()]: crate::mutators::Mutator::uses_error_map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::EnvironmentBuilder;
+    ///
+    /// let mut environment_builder = EnvironmentBuilder::default();
+    ///
+    /// environment_builder.set_error_guided(true);
+    /// ```
+    pub fn set_error_guided(&mut self, error_guided: bool) {
+        self.error_guided = error_guided;
+    }
+
+    /// Returns whether the environment needs to compute the error map for its mutators.
+    fn uses_error_map(&self) -> bool {
+        if self.adaptive_mutators.is_empty() {
+            self.error_guided || self.mutator.uses_error_map()
+        } else {
+            self.error_guided
+                || self
+                    .adaptive_mutators
+                    .iter()
+                    .any(|(_, mutator, _)| mutator.uses_error_map())
+        }
+    }
+
     /// This method build the environment and returns it.
     ///
     /// Returns an error if the builder has been ill-formed.
@@ -269,24 +415,56 @@ impl EnvironmentBuilder {
                         SaveCondition::Never => Box::new(|_| false),
                     };
 
-                // Safety: it's safe to unwrap the image because None-case is captured in the first arm.
-                let job_context = JobContext::new(
-                    self.image.unwrap(),
-                    self.mutator,
-                    self.fitness,
-                    self.color_mode,
-                );
+                let uses_error_map = self.uses_error_map();
 
                 let image_writer =
                     ImageWriter::new(self.output_directory, self.filename_prefix.to_owned());
 
+                if let Some(palette) = &self.palette {
+                    if !matches!(self.save_condition, SaveCondition::Never) {
+                        image_writer.write_palette(palette)?;
+                    }
+                }
+
+                let mut configuration = RunConfiguration::new(self.color_mode);
+                configuration.set_generation_size(self.generation_size);
+                configuration.set_blend_mode(self.blend_mode);
+                configuration.set_error_guided(uses_error_map);
+                if let Some(palette) = self.palette {
+                    configuration.set_palette(match self.color_mode {
+                        ColorMode::Rgb => palette,
+                        ColorMode::Grayscale => palette.to_grayscale(),
+                    });
+                }
+
+                let (mutators, selection) = if self.adaptive_mutators.is_empty() {
+                    (vec![self.mutator], None)
+                } else {
+                    let mut names = Vec::with_capacity(self.adaptive_mutators.len());
+                    let mut mutators = Vec::with_capacity(self.adaptive_mutators.len());
+                    let mut weights = Vec::with_capacity(self.adaptive_mutators.len());
+
+                    for (name, mutator, weight) in self.adaptive_mutators {
+                        names.push(name);
+                        mutators.push(mutator);
+                        weights.push(weight);
+                    }
+
+                    (mutators, Some(AdaptiveSelection::new(names, &weights)))
+                };
+
+                // Safety: it's safe to unwrap the image because None-case is captured in the first arm.
+                let job_context =
+                    JobContext::new(self.image.unwrap(), mutators, self.fitness, configuration);
+
                 Ok(Environment::new(
                     job_context,
-                    self.generation_size,
                     self.crossover,
                     self.display_condition,
                     image_writer,
                     should_save_specimen,
+                    selection,
+                    self.seed,
                 ))
             }
         }
@@ -294,12 +472,12 @@ impl EnvironmentBuilder {
 }
 
 impl Default for EnvironmentBuilder {
-    #[must_use]
     fn default() -> Self {
         Self {
             image: None,
             color_mode: ColorMode::Rgb,
             mutator: Box::<RectangleMutator>::default(),
+            adaptive_mutators: Vec::new(),
             fitness: Box::<SquareDistance>::default(),
             crossover: Box::<EqualHalfsCrossover>::default(),
             generation_size: 100,
@@ -308,6 +486,56 @@ impl Default for EnvironmentBuilder {
             output_directory: String::new(),
             filename_prefix: String::new(),
             save_condition: SaveCondition::Never,
+            palette: None,
+            seed: None,
+            blend_mode: BlendMode::Normal,
+            error_guided: false,
         }
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::mutators::{CircleMutator, Placement, ShapeStyle};
+
+    fn get_error_guided_style() -> ShapeStyle {
+        let mut style = ShapeStyle::default();
+        style.set_placement(Placement::ErrorGuided);
+
+        style
+    }
+
+    #[test]
+    fn usesErrorMap_uniformPlacement_errorMapNotComputed() {
+        let environment_builder = EnvironmentBuilder::default();
+
+        assert!(!environment_builder.uses_error_map());
+    }
+
+    #[test]
+    fn usesErrorMap_mutatorWithErrorGuidedPlacement_errorMapComputed() {
+        let mut environment_builder = EnvironmentBuilder::default();
+        environment_builder.set_mutator(Box::new(RectangleMutator::new(get_error_guided_style())));
+
+        assert!(environment_builder.uses_error_map());
+    }
+
+    #[test]
+    fn usesErrorMap_adaptiveMutatorWithErrorGuidedPlacement_errorMapComputed() {
+        let mut environment_builder = EnvironmentBuilder::default();
+        environment_builder
+            .add_adaptive_mutator("Rectangle", Box::<RectangleMutator>::default(), 1f64)
+            .unwrap();
+        environment_builder
+            .add_adaptive_mutator(
+                "Circle",
+                Box::new(CircleMutator::new(get_error_guided_style())),
+                1f64,
+            )
+            .unwrap();
+
+        assert!(environment_builder.uses_error_map());
+    }
+}
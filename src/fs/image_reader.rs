@@ -1,9 +1,11 @@
-use anyhow::{Ok, Result};
-use image::{io::Reader, Pixel as RgbPixel};
+use std::fs;
 
-use crate::models::{Image, Pixel};
+use anyhow::{Error, Ok, Result};
+use image::{io::Reader, ImageFormat, Pixel as RgbPixel};
 
-/// This struct can be used to load an image from the filesystem.
+use crate::models::{Brush, Image, Pixel};
+
+/// This struct can be used to load an image or brushes from the filesystem.
 #[derive(Debug)]
 pub struct ImageReader;
 
@@ -32,4 +34,61 @@ impl ImageReader {
             pixels,
         ))
     }
+
+    /// This method loads the brush pointed by the given path.
+    ///
+    /// The alpha channel of the image is used as the mask of the brush. Images without alpha channel are painted
+    /// where they are dark, see [Brush::from_luminance].
+    pub fn load_brush(path: &str) -> Result<Brush> {
+        let image = Reader::open(path)?.with_guessed_format()?.decode()?;
+
+        if image.color().has_alpha() {
+            let image_buffer = image.to_rgba8();
+
+            let (pixels, mask) = image_buffer
+                .pixels()
+                .map(|p| (Pixel::new(p[0], p[1], p[2]), p[3]))
+                .unzip::<_, _, Vec<Pixel>, Vec<u8>>();
+
+            Brush::new(
+                Image::new(image.height() as usize, image.width() as usize, pixels),
+                mask,
+            )
+        } else {
+            let pixels = image
+                .to_rgb8()
+                .pixels()
+                .map(|p| Pixel::new(p[0], p[1], p[2]))
+                .collect::<Vec<Pixel>>();
+
+            Brush::from_luminance(Image::new(
+                image.height() as usize,
+                image.width() as usize,
+                pixels,
+            ))
+        }
+    }
+
+    /// This method loads all images from the given directory as brushes, ordered by their file names.
+    ///
+    /// Files which aren't images are skipped. Returns an error if the directory contains no images.
+    pub fn load_brushes(path: &str) -> Result<Vec<Brush>> {
+        let mut paths = fs::read_dir(path)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?;
+        paths.retain(|path| path.is_file() && ImageFormat::from_path(path).is_ok());
+        paths.sort();
+
+        if paths.is_empty() {
+            return Err(Error::msg(format!(
+                "There are no brushes in the directory: {}.",
+                path
+            )));
+        }
+
+        paths
+            .iter()
+            .map(|path| Self::load_brush(&path.to_string_lossy()))
+            .collect()
+    }
 }
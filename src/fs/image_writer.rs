@@ -1,9 +1,9 @@
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 
 use anyhow::Result;
 use image::ColorType;
 
-use crate::models::Image;
+use crate::models::{Image, Palette};
 
 #[derive(Debug)]
 pub(crate) struct ImageWriter {
@@ -36,4 +36,14 @@ impl ImageWriter {
 
         Ok(())
     }
+
+    pub(crate) fn write_palette(&self, palette: &Palette) -> Result<()> {
+        let full_path = self
+            .output_directory
+            .join(format!("{}palette.gpl", self.filename_prefix));
+
+        fs::write(full_path, palette.to_string())?;
+
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 mod image_reader;
 pub use image_reader::ImageReader;
 
+mod palette_reader;
+pub use palette_reader::PaletteReader;
+
 mod image_writer;
 pub(crate) use image_writer::ImageWriter;
 
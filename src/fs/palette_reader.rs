@@ -0,0 +1,19 @@
+use std::fs;
+
+use anyhow::Result;
+
+use crate::models::Palette;
+
+/// This struct can be used to load a palette from the filesystem.
+#[derive(Debug)]
+pub struct PaletteReader;
+
+impl PaletteReader {
+    /// This method loads the palette pointed by the given path.
+    ///
+    /// The file must be either a GIMP palette (GPL) or a list of hex color codes (e.g. "#ff8800"), separated by
+    /// whitespace.
+    pub fn load(path: &str) -> Result<Palette> {
+        fs::read_to_string(path)?.parse::<Palette>()
+    }
+}
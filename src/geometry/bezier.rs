@@ -0,0 +1,208 @@
+use super::{Bounds, Point, Polygon, Shape, Stroke};
+
+/// Number of straight segments used to approximate a single curve.
+const SEGMENTS_PER_CURVE: usize = 32;
+
+/// A Bézier curve of any degree, e.g. quadratic with 3 control points or cubic with 4 control points.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Bezier {
+    control_points: Vec<Point>,
+}
+
+impl Bezier {
+    #[must_use]
+    pub fn new(control_points: Vec<Point>) -> Self {
+        assert!(
+            control_points.len() > 1,
+            "Bézier curve must have at least two control points."
+        );
+
+        Self { control_points }
+    }
+
+    /// Returns the control points of the curve, from the start point to the end point.
+    pub fn control_points(&self) -> &[Point] {
+        &self.control_points
+    }
+
+    /// Returns the point of the curve for `t` in range `[0, 1]`, calculated with de Casteljau's algorithm.
+    pub fn point_at(&self, t: f64) -> Point {
+        let mut points = self.control_points.clone();
+
+        for n in (1..points.len()).rev() {
+            for i in 0..n {
+                points[i] = Point::new(
+                    points[i].x + (points[i + 1].x - points[i].x) * t,
+                    points[i].y + (points[i + 1].y - points[i].y) * t,
+                );
+            }
+        }
+
+        points[0]
+    }
+
+    /// Approximates the curve with a polyline.
+    pub fn flatten(&self) -> Vec<Point> {
+        (0..=SEGMENTS_PER_CURVE)
+            .map(|i| self.point_at(i as f64 / SEGMENTS_PER_CURVE as f64))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Outline {
+    Stroked(Stroke),
+    Filled(Polygon),
+}
+
+/// A path made of Bézier curves, which is either stroked or filled.
+///
+/// The path keeps its control points, while it's drawn with a polyline approximating the curves.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BezierPath {
+    curves: Vec<Bezier>,
+    widths: Option<(f64, f64)>,
+    outline: Outline,
+}
+
+impl BezierPath {
+    /// Creates a stroked path, whose width changes linearly from `start_width` to `end_width`.
+    #[must_use]
+    pub fn stroked(curves: Vec<Bezier>, start_width: f64, end_width: f64) -> Self {
+        let points = Self::flatten(&curves);
+
+        Self {
+            curves,
+            widths: Some((start_width, end_width)),
+            outline: Outline::Stroked(Stroke::tapered(points, start_width, end_width)),
+        }
+    }
+
+    /// Creates a filled path. The path is closed with a straight line from the last point to the first one and
+    /// filled according to the even-odd rule.
+    #[must_use]
+    pub fn filled(curves: Vec<Bezier>) -> Self {
+        let points = Self::flatten(&curves);
+
+        Self {
+            curves,
+            widths: None,
+            outline: Outline::Filled(Polygon::new(points)),
+        }
+    }
+
+    /// Returns the curves of the path.
+    pub fn curves(&self) -> &[Bezier] {
+        &self.curves
+    }
+
+    /// Returns the widths at the start and at the end of a stroked path, or `None` if the path is filled.
+    pub fn widths(&self) -> Option<(f64, f64)> {
+        self.widths
+    }
+
+    fn flatten(curves: &[Bezier]) -> Vec<Point> {
+        curves.iter().flat_map(|curve| curve.flatten()).collect()
+    }
+}
+
+impl Shape for BezierPath {
+    fn bounds(&self) -> Bounds {
+        // A Bézier curve always lies within the convex hull of its control points.
+        let half_width = self
+            .widths
+            .map_or(0f64, |(start_width, end_width)| start_width.max(end_width))
+            / 2f64;
+
+        self.curves
+            .iter()
+            .flat_map(|curve| curve.control_points.iter())
+            .fold(
+                Bounds {
+                    min_x: f64::INFINITY,
+                    min_y: f64::INFINITY,
+                    max_x: f64::NEG_INFINITY,
+                    max_y: f64::NEG_INFINITY,
+                },
+                |bounds, p| Bounds {
+                    min_x: bounds.min_x.min(p.x - half_width),
+                    min_y: bounds.min_y.min(p.y - half_width),
+                    max_x: bounds.max_x.max(p.x + half_width),
+                    max_y: bounds.max_y.max(p.y + half_width),
+                },
+            )
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        match &self.outline {
+            Outline::Stroked(stroke) => stroke.signed_distance(x, y),
+            Outline::Filled(polygon) => polygon.signed_distance(x, y),
+        }
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        match &self.outline {
+            Outline::Stroked(stroke) => stroke.contains(x, y),
+            Outline::Filled(polygon) => polygon.contains(x, y),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pointAt_quadraticCurve_endpointsAndMidpointReturned() {
+        let curve = Bezier::new(vec![
+            Point::new(0f64, 0f64),
+            Point::new(5f64, 10f64),
+            Point::new(10f64, 0f64),
+        ]);
+
+        assert_eq!(Point::new(0f64, 0f64), curve.point_at(0f64));
+        assert_eq!(Point::new(5f64, 5f64), curve.point_at(0.5));
+        assert_eq!(Point::new(10f64, 0f64), curve.point_at(1f64));
+    }
+
+    #[test]
+    fn pointAt_cubicCurve_midpointReturned() {
+        let curve = Bezier::new(vec![
+            Point::new(0f64, 0f64),
+            Point::new(0f64, 8f64),
+            Point::new(8f64, 8f64),
+            Point::new(8f64, 0f64),
+        ]);
+
+        assert_eq!(Point::new(4f64, 6f64), curve.point_at(0.5));
+    }
+
+    #[test]
+    fn bounds_strokedPath_controlPointsExpandedByWidth() {
+        let curve = Bezier::new(vec![
+            Point::new(0f64, 0f64),
+            Point::new(5f64, 10f64),
+            Point::new(10f64, 0f64),
+        ]);
+        let path = BezierPath::stroked(vec![curve], 2f64, 4f64);
+
+        let bounds = path.bounds();
+
+        assert_eq!(-2f64, bounds.min_x);
+        assert_eq!(12f64, bounds.max_y);
+    }
+
+    #[test]
+    fn contains_filledPath_pointsInsideCurveCovered() {
+        let curve = Bezier::new(vec![
+            Point::new(0f64, 0f64),
+            Point::new(5f64, 10f64),
+            Point::new(10f64, 0f64),
+        ]);
+        let path = BezierPath::filled(vec![curve]);
+
+        assert!(path.contains(5f64, 2f64));
+        assert!(!path.contains(5f64, 6f64));
+    }
+}
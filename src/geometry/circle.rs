@@ -0,0 +1,23 @@
+use super::{Bounds, Shape};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Circle {
+    pub x: f64,
+    pub y: f64,
+    pub r: f64,
+}
+
+impl Shape for Circle {
+    fn bounds(&self) -> Bounds {
+        Bounds {
+            min_x: self.x - self.r,
+            min_y: self.y - self.r,
+            max_x: self.x + self.r,
+            max_y: self.y + self.r,
+        }
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        (x - self.x).hypot(y - self.y) - self.r
+    }
+}
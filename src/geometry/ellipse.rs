@@ -0,0 +1,113 @@
+use super::{Bounds, Shape};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Ellipse {
+    pub x: f64,
+    pub y: f64,
+    pub semi_major: f64,
+    pub semi_minor: f64,
+    /// Rotation of the major axis in radians, measured clockwise from the X axis.
+    pub angle: f64,
+}
+
+impl Ellipse {
+    /// Transforms the point to the coordinate system in which the ellipse is centered and axis-aligned.
+    fn local_coordinates(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sin, cos) = self.angle.sin_cos();
+        let (dx, dy) = (x - self.x, y - self.y);
+
+        (dx * cos + dy * sin, dy * cos - dx * sin)
+    }
+}
+
+impl Shape for Ellipse {
+    fn bounds(&self) -> Bounds {
+        let (sin, cos) = self.angle.sin_cos();
+        let (a, b) = (self.semi_major, self.semi_minor);
+
+        let half_width = ((a * cos).powi(2) + (b * sin).powi(2)).sqrt();
+        let half_height = ((a * sin).powi(2) + (b * cos).powi(2)).sqrt();
+
+        Bounds {
+            min_x: self.x - half_width,
+            min_y: self.y - half_height,
+            max_x: self.x + half_width,
+            max_y: self.y + half_height,
+        }
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let (u, v) = self.local_coordinates(x, y);
+        let (a, b) = (self.semi_major, self.semi_minor);
+
+        // Approximation of the distance based on the gradient of the implicit equation, which is exact on the edge.
+        let k0 = (u / a).hypot(v / b);
+        let k1 = (u / (a * a)).hypot(v / (b * b));
+
+        if k1 == 0f64 {
+            -a.min(b)
+        } else {
+            k0 * (k0 - 1f64) / k1
+        }
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let (u, v) = self.local_coordinates(x, y);
+
+        (u / self.semi_major).powi(2) + (v / self.semi_minor).powi(2) < 1f64
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[test]
+    fn contains_rotatedByRightAngle_axesSwapped() {
+        let ellipse = Ellipse {
+            x: 10f64,
+            y: 10f64,
+            semi_major: 8f64,
+            semi_minor: 2f64,
+            angle: FRAC_PI_2,
+        };
+
+        assert!(ellipse.contains(10f64, 17f64));
+        assert!(!ellipse.contains(17f64, 10f64));
+    }
+
+    #[test]
+    fn bounds_rotatedByRightAngle_boundsSwapped() {
+        let ellipse = Ellipse {
+            x: 10f64,
+            y: 10f64,
+            semi_major: 8f64,
+            semi_minor: 2f64,
+            angle: FRAC_PI_2,
+        };
+
+        let bounds = ellipse.bounds();
+
+        assert!((bounds.min_x - 8f64).abs() < 1e-9);
+        assert!((bounds.max_x - 12f64).abs() < 1e-9);
+        assert!((bounds.min_y - 2f64).abs() < 1e-9);
+        assert!((bounds.max_y - 18f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn signedDistance_pointsOnAxes_distanceToEdgeReturned() {
+        let ellipse = Ellipse {
+            x: 0f64,
+            y: 0f64,
+            semi_major: 8f64,
+            semi_minor: 4f64,
+            angle: 0f64,
+        };
+
+        assert!((ellipse.signed_distance(9f64, 0f64) - 1f64).abs() < 0.1);
+        assert!((ellipse.signed_distance(0f64, 3f64) + 1f64).abs() < 0.1);
+    }
+}
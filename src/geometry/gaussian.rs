@@ -0,0 +1,92 @@
+use super::{Bounds, Ellipse, Shape};
+
+/// Number of standard deviations after which the density of a splat is cut off.
+const CUTOFF: f64 = 3f64;
+
+/// A soft-edged blob, whose coverage follows a two-dimensional Gaussian distribution.
+///
+/// The edge of the splat, as seen by [Shape::signed_distance], is the ellipse one standard deviation away from the
+/// center, while its coverage fades out smoothly up to three standard deviations away.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Gaussian {
+    pub x: f64,
+    pub y: f64,
+    pub sigma_major: f64,
+    pub sigma_minor: f64,
+    /// Rotation of the major axis in radians, measured clockwise from the X axis.
+    pub angle: f64,
+}
+
+impl Gaussian {
+    fn get_ellipse(&self, scale: f64) -> Ellipse {
+        Ellipse {
+            x: self.x,
+            y: self.y,
+            semi_major: self.sigma_major * scale,
+            semi_minor: self.sigma_minor * scale,
+            angle: self.angle,
+        }
+    }
+}
+
+impl Shape for Gaussian {
+    fn bounds(&self) -> Bounds {
+        self.get_ellipse(CUTOFF).bounds()
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        self.get_ellipse(1f64).signed_distance(x, y)
+    }
+
+    fn coverage(&self, x: f64, y: f64, _anti_aliasing: bool) -> f64 {
+        let (sin, cos) = self.angle.sin_cos();
+        let (dx, dy) = (x - self.x, y - self.y);
+        let u = (dx * cos + dy * sin) / self.sigma_major;
+        let v = (dy * cos - dx * sin) / self.sigma_minor;
+
+        let square_distance = u * u + v * v;
+        if square_distance > CUTOFF * CUTOFF {
+            0f64
+        } else {
+            (-square_distance / 2f64).exp()
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    fn get_gaussian() -> Gaussian {
+        Gaussian {
+            x: 10f64,
+            y: 10f64,
+            sigma_major: 4f64,
+            sigma_minor: 2f64,
+            angle: 0f64,
+        }
+    }
+
+    #[test]
+    fn coverage_center_fullyCovered() {
+        assert_eq!(1f64, get_gaussian().coverage(10f64, 10f64, false));
+    }
+
+    #[test]
+    fn coverage_oneSigmaAway_coverageFollowsDistribution() {
+        let gaussian = get_gaussian();
+        let expected = (-0.5f64).exp();
+
+        assert!((gaussian.coverage(14f64, 10f64, false) - expected).abs() < 1e-9);
+        assert!((gaussian.coverage(10f64, 12f64, true) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn coverage_beyondCutoff_notCovered() {
+        let gaussian = get_gaussian();
+
+        assert_eq!(0f64, gaussian.coverage(10f64, 16.5, false));
+        assert_eq!(16f64, gaussian.bounds().max_y);
+    }
+}
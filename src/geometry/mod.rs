@@ -0,0 +1,35 @@
+mod point;
+pub(crate) use point::Point;
+
+mod shape;
+pub(crate) use shape::{Bounds, Shape};
+
+mod rasterizer;
+pub(crate) use rasterizer::rasterize;
+
+mod outline;
+pub(crate) use outline::Outline;
+
+mod circle;
+pub(crate) use circle::Circle;
+
+mod rectangle;
+pub(crate) use rectangle::Rectangle;
+
+mod triangle;
+pub(crate) use triangle::Triangle;
+
+mod ellipse;
+pub(crate) use ellipse::Ellipse;
+
+mod gaussian;
+pub(crate) use gaussian::Gaussian;
+
+mod polygon;
+pub(crate) use polygon::Polygon;
+
+mod stroke;
+pub(crate) use stroke::Stroke;
+
+mod bezier;
+pub(crate) use bezier::{Bezier, BezierPath};
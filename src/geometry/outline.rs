@@ -0,0 +1,71 @@
+use super::{Bounds, Shape};
+
+/// Band of the given width along the edge of a closed shape, lying on its inner side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Outline<'a, S: ?Sized> {
+    pub shape: &'a S,
+    pub width: f64,
+}
+
+impl<'a, S> Shape for Outline<'a, S>
+where
+    S: Shape + ?Sized,
+{
+    fn bounds(&self) -> Bounds {
+        self.shape.bounds()
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let distance = self.shape.signed_distance(x, y);
+
+        // Points deeper inside the shape than the width of the band lie outside of the outline.
+        distance.max(-distance - self.width)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::geometry::{rasterize, Rectangle};
+
+    #[test]
+    fn rasterize_rectangleOutline_onlyEdgePixelsPlotted() {
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 5f64,
+            max_y: 5f64,
+        };
+        let outline = Outline {
+            shape: &rectangle,
+            width: 1f64,
+        };
+
+        let mut pixels = Vec::new();
+        rasterize(&outline, 8, 8, false, |x, y, _| pixels.push((x, y)));
+
+        assert_eq!(16, pixels.len());
+        assert!(pixels
+            .iter()
+            .all(|&(x, y)| x == 0 || y == 0 || x == 4 || y == 4));
+    }
+
+    #[test]
+    fn signedDistance_pointOnEdge_insideOutline() {
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 10f64,
+            max_y: 10f64,
+        };
+        let outline = Outline {
+            shape: &rectangle,
+            width: 2f64,
+        };
+
+        assert!(outline.contains(1f64, 5f64));
+        assert!(!outline.contains(5f64, 5f64));
+        assert!(!outline.contains(11f64, 5f64));
+    }
+}
@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    #[must_use]
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the distance from this point to the segment between `a` and `b`.
+    pub fn distance_to_segment(&self, a: &Point, b: &Point) -> f64 {
+        self.project_on_segment(a, b).0
+    }
+
+    /// Finds the point of the segment between `a` and `b` nearest to this point.
+    ///
+    /// Returns the distance to that point and its position on the segment in range `[0, 1]`, where 0 means `a` and 1
+    /// means `b`.
+    pub fn project_on_segment(&self, a: &Point, b: &Point) -> (f64, f64) {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let length_squared = dx * dx + dy * dy;
+
+        let t = if length_squared > 0f64 {
+            (((self.x - a.x) * dx + (self.y - a.y) * dy) / length_squared).clamp(0f64, 1f64)
+        } else {
+            0f64
+        };
+
+        let distance = (self.x - (a.x + t * dx)).hypot(self.y - (a.y + t * dy));
+
+        (distance, t)
+    }
+
+    /// Returns the distance between two points.
+    pub fn distance(&self, other: &Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
@@ -0,0 +1,114 @@
+use super::{Bounds, Point, Shape};
+
+/// A polygon filled according to the even-odd rule.
+///
+/// The polygon may be self-intersecting. A point belongs to the polygon if a ray cast from it crosses the edges an odd
+/// number of times.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    #[must_use]
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&Point, &Point)> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+}
+
+impl Shape for Polygon {
+    fn bounds(&self) -> Bounds {
+        self.vertices.iter().fold(
+            Bounds {
+                min_x: f64::INFINITY,
+                min_y: f64::INFINITY,
+                max_x: f64::NEG_INFINITY,
+                max_y: f64::NEG_INFINITY,
+            },
+            |bounds, p| Bounds {
+                min_x: bounds.min_x.min(p.x),
+                min_y: bounds.min_y.min(p.y),
+                max_x: bounds.max_x.max(p.x),
+                max_y: bounds.max_y.max(p.y),
+            },
+        )
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let p = Point::new(x, y);
+
+        let distance = self
+            .edges()
+            .map(|(a, b)| p.distance_to_segment(a, b))
+            .fold(f64::INFINITY, f64::min);
+
+        if self.contains(x, y) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        // Each edge covers the half-open range [min_y, max_y), so a ray passing through a vertex is counted once.
+        self.edges()
+            .filter(|(a, b)| (a.y <= y) != (b.y <= y))
+            .filter(|(a, b)| x < a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y))
+            .count()
+            % 2
+            == 1
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    fn pentagram() -> Polygon {
+        let vertices = [
+            Point::new(5f64, 0f64),
+            Point::new(8f64, 9f64),
+            Point::new(0f64, 3.5),
+            Point::new(10f64, 3.5),
+            Point::new(2f64, 9f64),
+        ];
+
+        Polygon::new(vertices.to_vec())
+    }
+
+    #[test]
+    fn contains_selfIntersectingPolygon_centerIsOutside() {
+        let polygon = pentagram();
+
+        assert!(!polygon.contains(5f64, 5f64));
+    }
+
+    #[test]
+    fn contains_selfIntersectingPolygon_spikesAreInside() {
+        let polygon = pentagram();
+
+        assert!(polygon.contains(5f64, 1f64));
+        assert!(polygon.contains(1f64, 3.7));
+        assert!(polygon.contains(9f64, 3.7));
+    }
+
+    #[test]
+    fn signedDistance_squareCenter_negativeDistanceToEdge() {
+        let polygon = Polygon::new(vec![
+            Point::new(0f64, 0f64),
+            Point::new(4f64, 0f64),
+            Point::new(4f64, 4f64),
+            Point::new(0f64, 4f64),
+        ]);
+
+        assert_eq!(-2f64, polygon.signed_distance(2f64, 2f64));
+        assert_eq!(1f64, polygon.signed_distance(5f64, 2f64));
+    }
+}
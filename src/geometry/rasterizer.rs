@@ -0,0 +1,94 @@
+use super::Shape;
+
+/// Calls `plot` for every pixel of a `width` x `height` canvas covered by `shape`.
+///
+/// `plot` receives the coordinates of the pixel and its coverage in range `(0, 1]`, as returned by [Shape::coverage].
+pub(crate) fn rasterize<S, F>(
+    shape: &S,
+    width: usize,
+    height: usize,
+    anti_aliasing: bool,
+    mut plot: F,
+) where
+    S: Shape + ?Sized,
+    F: FnMut(usize, usize, f64),
+{
+    let bounds = shape.bounds();
+
+    let min_x = bounds.min_x.floor().max(0f64) as usize;
+    let min_y = bounds.min_y.floor().max(0f64) as usize;
+    let max_x = (bounds.max_x.ceil().max(0f64) as usize).min(width);
+    let max_y = (bounds.max_y.ceil().max(0f64) as usize).min(height);
+
+    for y in min_y..max_y {
+        let center_y = y as f64 + 0.5;
+
+        for x in min_x..max_x {
+            let center_x = x as f64 + 0.5;
+
+            let coverage = shape.coverage(center_x, center_y, anti_aliasing);
+            if coverage > 0f64 {
+                plot(x, y, coverage);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::geometry::Circle;
+
+    fn collect(shape: &Circle, anti_aliasing: bool) -> Vec<(usize, usize, f64)> {
+        let mut pixels = Vec::new();
+        rasterize(shape, 10, 10, anti_aliasing, |x, y, coverage| {
+            pixels.push((x, y, coverage))
+        });
+
+        pixels
+    }
+
+    #[test]
+    fn rasterize_withoutAntiAliasing_allPixelsFullyCovered() {
+        let circle = Circle {
+            x: 5f64,
+            y: 5f64,
+            r: 3.3,
+        };
+
+        let pixels = collect(&circle, false);
+
+        assert!(!pixels.is_empty());
+        assert!(pixels.iter().all(|(_, _, coverage)| *coverage == 1f64));
+    }
+
+    #[test]
+    fn rasterize_withAntiAliasing_edgePixelsPartiallyCovered() {
+        let circle = Circle {
+            x: 5f64,
+            y: 5f64,
+            r: 3.3,
+        };
+
+        let pixels = collect(&circle, true);
+
+        assert!(pixels.iter().any(|(_, _, coverage)| *coverage < 1f64));
+        assert!(pixels.contains(&(5, 5, 1f64)));
+        assert!(pixels.len() > collect(&circle, false).len());
+    }
+
+    #[test]
+    fn rasterize_shapePartiallyOutsideCanvas_onlyPixelsOnCanvasPlotted() {
+        let circle = Circle {
+            x: 0f64,
+            y: 9f64,
+            r: 4f64,
+        };
+
+        let pixels = collect(&circle, true);
+
+        assert!(!pixels.is_empty());
+        assert!(pixels.iter().all(|(x, y, _)| *x < 10 && *y < 10));
+    }
+}
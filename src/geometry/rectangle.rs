@@ -0,0 +1,68 @@
+use super::{Bounds, Shape};
+
+/// Axis-aligned rectangle spanning from `(min_x, min_y)` to `(max_x, max_y)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Rectangle {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Shape for Rectangle {
+    fn bounds(&self) -> Bounds {
+        Bounds {
+            min_x: self.min_x,
+            min_y: self.min_y,
+            max_x: self.max_x,
+            max_y: self.max_y,
+        }
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let half_width = (self.max_x - self.min_x) / 2f64;
+        let half_height = (self.max_y - self.min_y) / 2f64;
+
+        let dx = (x - (self.min_x + half_width)).abs() - half_width;
+        let dy = (y - (self.min_y + half_height)).abs() - half_height;
+
+        dx.max(0f64).hypot(dy.max(0f64)) + dx.max(dy).min(0f64)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::geometry::rasterize;
+
+    #[test]
+    fn rasterize_integerBounds_exactlyCoveredPixelsPlotted() {
+        let rectangle = Rectangle {
+            min_x: 1f64,
+            min_y: 2f64,
+            max_x: 4f64,
+            max_y: 3f64,
+        };
+
+        let mut pixels = Vec::new();
+        rasterize(&rectangle, 8, 6, true, |x, y, coverage| {
+            pixels.push((x, y, coverage))
+        });
+
+        assert_eq!(vec![(1, 2, 1f64), (2, 2, 1f64), (3, 2, 1f64)], pixels);
+    }
+
+    #[test]
+    fn signedDistance_pointOutsideCorner_distanceToCornerReturned() {
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 2f64,
+            max_y: 2f64,
+        };
+
+        assert!((rectangle.signed_distance(5f64, 6f64) - 5f64).abs() < 1e-9);
+        assert!((rectangle.signed_distance(1f64, 1.5) + 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,42 @@
+/// Axis-aligned bounding box of a shape, in pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Bounds {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+/// A closed shape on a continuous plane.
+///
+/// Pixel `(x, y)` covers the square `[x, x + 1) x [y, y + 1)`, so its center lies at `(x + 0.5, y + 0.5)`.
+pub(crate) trait Shape {
+    /// Returns the smallest box which contains the whole shape.
+    fn bounds(&self) -> Bounds;
+
+    /// Returns the signed distance from the point to the edge of the shape.
+    ///
+    /// The distance is negative inside the shape and positive outside of it. Implementations may approximate the
+    /// distance, as long as it is accurate close to the edge.
+    fn signed_distance(&self, x: f64, y: f64) -> f64;
+
+    /// Checks whether the point lies inside the shape.
+    fn contains(&self, x: f64, y: f64) -> bool {
+        self.signed_distance(x, y) < 0f64
+    }
+
+    /// Returns the part of the pixel centered at the point covered by the shape, in range `[0, 1]`.
+    ///
+    /// Without anti-aliasing a pixel is either fully covered or not at all, depending on whether its center lies inside
+    /// the shape. With anti-aliasing the coverage of pixels along the edge is estimated from their distance to the
+    /// edge. Shapes with soft edges may override this method.
+    fn coverage(&self, x: f64, y: f64, anti_aliasing: bool) -> f64 {
+        if anti_aliasing {
+            (0.5 - self.signed_distance(x, y)).clamp(0f64, 1f64)
+        } else if self.contains(x, y) {
+            1f64
+        } else {
+            0f64
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Bounds, Point, Shape};
+
+/// A polyline with round caps and joins.
+///
+/// The width of the polyline may change linearly between its points.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Stroke {
+    points: Vec<Point>,
+    widths: Vec<f64>,
+}
+
+impl Stroke {
+    #[must_use]
+    pub fn new(points: Vec<Point>, width: f64) -> Self {
+        let widths = vec![width; points.len()];
+
+        Self { points, widths }
+    }
+
+    /// Creates a polyline whose width changes linearly along its length, from `start_width` to `end_width`.
+    #[must_use]
+    pub fn tapered(points: Vec<Point>, start_width: f64, end_width: f64) -> Self {
+        let distances = points
+            .iter()
+            .zip(points.iter().skip(1))
+            .scan(0f64, |length, (a, b)| {
+                *length += a.distance(b);
+                Some(*length)
+            })
+            .collect::<Vec<f64>>();
+        let total_length = distances.last().copied().unwrap_or_default();
+
+        let widths = std::iter::once(0f64)
+            .chain(distances)
+            .map(|distance| {
+                let t = if total_length > 0f64 {
+                    distance / total_length
+                } else {
+                    0f64
+                };
+
+                start_width + (end_width - start_width) * t
+            })
+            .collect();
+
+        Self { points, widths }
+    }
+
+    /// Returns pairs of indices of points connected with a segment.
+    fn segments(&self) -> impl Iterator<Item = (usize, usize)> {
+        // A single point is treated as a segment of zero length, i.e. a dot.
+        let last = self.points.len().saturating_sub(1);
+
+        (0..last.max(1)).map(move |i| (i, (i + 1).min(last)))
+    }
+}
+
+impl Shape for Stroke {
+    fn bounds(&self) -> Bounds {
+        self.points.iter().zip(self.widths.iter()).fold(
+            Bounds {
+                min_x: f64::INFINITY,
+                min_y: f64::INFINITY,
+                max_x: f64::NEG_INFINITY,
+                max_y: f64::NEG_INFINITY,
+            },
+            |bounds, (p, width)| Bounds {
+                min_x: bounds.min_x.min(p.x - width / 2f64),
+                min_y: bounds.min_y.min(p.y - width / 2f64),
+                max_x: bounds.max_x.max(p.x + width / 2f64),
+                max_y: bounds.max_y.max(p.y + width / 2f64),
+            },
+        )
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let p = Point::new(x, y);
+
+        self.segments()
+            .map(|(a, b)| {
+                let (distance, t) = p.project_on_segment(&self.points[a], &self.points[b]);
+                distance - (self.widths[a] + (self.widths[b] - self.widths[a]) * t) / 2f64
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn signedDistance_pointBeyondEndOfSegment_roundCapApplied() {
+        let stroke = Stroke::new(vec![Point::new(0f64, 0f64), Point::new(10f64, 0f64)], 4f64);
+
+        assert_eq!(-2f64, stroke.signed_distance(5f64, 0f64));
+        assert_eq!(1f64, stroke.signed_distance(13f64, 0f64));
+        assert!(stroke.contains(11f64, 1f64));
+        assert!(!stroke.contains(11.5, 1.5));
+    }
+
+    #[test]
+    fn signedDistance_singlePoint_dotDrawn() {
+        let stroke = Stroke::new(vec![Point::new(3f64, 3f64)], 2f64);
+
+        assert_eq!(-1f64, stroke.signed_distance(3f64, 3f64));
+        assert_eq!(2f64, stroke.signed_distance(6f64, 3f64));
+    }
+
+    #[test]
+    fn signedDistance_polyline_distanceToNearestSegment() {
+        let stroke = Stroke::new(
+            vec![
+                Point::new(0f64, 0f64),
+                Point::new(10f64, 0f64),
+                Point::new(10f64, 10f64),
+            ],
+            2f64,
+        );
+
+        assert_eq!(1f64, stroke.signed_distance(8f64, 5f64));
+        assert_eq!(-1f64, stroke.signed_distance(10f64, 5f64));
+    }
+
+    #[test]
+    fn signedDistance_taperedStroke_widthChangesAlongStroke() {
+        let stroke = Stroke::tapered(
+            vec![
+                Point::new(0f64, 0f64),
+                Point::new(10f64, 0f64),
+                Point::new(20f64, 0f64),
+            ],
+            2f64,
+            6f64,
+        );
+
+        assert_eq!(-1f64, stroke.signed_distance(0f64, 0f64));
+        assert_eq!(-2f64, stroke.signed_distance(10f64, 0f64));
+        assert_eq!(-3f64, stroke.signed_distance(20f64, 0f64));
+        assert!(stroke.contains(15f64, 2f64));
+        assert!(!stroke.contains(5f64, 2f64));
+    }
+}
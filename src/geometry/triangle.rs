@@ -0,0 +1,243 @@
+use super::{Bounds, Point, Shape};
+
+/// Returns a value which is positive if `p` lies on the left side of the directed edge from `a` to `b`, negative if
+/// it lies on the right side and zero if all three points are collinear.
+fn edge_function(a: &Point, b: &Point, p: &Point) -> f64 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Triangle {
+    vertices: [Point; 3],
+}
+
+impl Triangle {
+    #[must_use]
+    pub fn new(a: Point, b: Point, c: Point) -> Self {
+        // Vertices are kept in the order for which the interior lies on the left side of every edge.
+        if edge_function(&a, &b, &c) < 0f64 {
+            Self {
+                vertices: [a, c, b],
+            }
+        } else {
+            Self {
+                vertices: [a, b, c],
+            }
+        }
+    }
+
+    fn edges(&self) -> [(&Point, &Point); 3] {
+        let [a, b, c] = &self.vertices;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+/// Checks whether the edge from `a` to `b` is either a top or a left edge of the triangle.
+///
+/// A top edge is horizontal and the rest of the triangle lies below it. A left edge is not horizontal and the rest of
+/// the triangle lies on its right side. Points lying exactly on an edge belong to the triangle only if the edge is a
+/// top or a left edge, so triangles sharing an edge never cover the same pixel twice and never leave a gap between
+/// them.
+fn is_top_left(a: &Point, b: &Point) -> bool {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+
+    (dy == 0f64 && dx > 0f64) || dy < 0f64
+}
+
+impl Shape for Triangle {
+    fn bounds(&self) -> Bounds {
+        let xs = self.vertices.map(|p| p.x);
+        let ys = self.vertices.map(|p| p.y);
+
+        Bounds {
+            min_x: xs.iter().copied().fold(f64::INFINITY, f64::min),
+            min_y: ys.iter().copied().fold(f64::INFINITY, f64::min),
+            max_x: xs.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            max_y: ys.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let p = Point::new(x, y);
+
+        if self.contains(x, y) {
+            // Inside a convex shape the nearest edge is the one with the nearest supporting line.
+            self.edges()
+                .iter()
+                .map(|(a, b)| -edge_function(a, b, &p) / (b.x - a.x).hypot(b.y - a.y))
+                .fold(f64::NEG_INFINITY, f64::max)
+        } else {
+            self.edges()
+                .iter()
+                .map(|(a, b)| p.distance_to_segment(a, b))
+                .fold(f64::INFINITY, f64::min)
+        }
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let p = Point::new(x, y);
+
+        self.edges().iter().all(|(a, b)| {
+            let w = edge_function(a, b, &p);
+            w > 0f64 || (w == 0f64 && is_top_left(a, b))
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::geometry::rasterize;
+
+    const WIDTH: usize = 8;
+    const HEIGHT: usize = 6;
+
+    fn triangle(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Triangle {
+        Triangle::new(
+            Point::new(a.0, a.1),
+            Point::new(b.0, b.1),
+            Point::new(c.0, c.1),
+        )
+    }
+
+    fn render(triangles: &[Triangle]) -> Vec<String> {
+        let mut counts = [[0u8; WIDTH]; HEIGHT];
+
+        for triangle in triangles {
+            rasterize(triangle, WIDTH, HEIGHT, false, |x, y, _| counts[y][x] += 1);
+        }
+
+        counts
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|count| match count {
+                        0 => '.',
+                        1 => '#',
+                        _ => '2',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rasterize_rightTriangle_pixelsOnHypotenuseNotCovered() {
+        let t = triangle((0f64, 0f64), (6f64, 0f64), (0f64, 6f64));
+
+        #[rustfmt::skip]
+        let expected = vec![
+            "#####...",
+            "####....",
+            "###.....",
+            "##......",
+            "#.......",
+            "........",
+        ];
+        assert_eq!(expected, render(&[t]));
+    }
+
+    #[test]
+    fn rasterize_verticesInPixelCenters_onlyTopLeftEdgesCovered() {
+        let t = triangle((0.5, 0.5), (7.5, 2.5), (2.5, 5.5));
+
+        #[rustfmt::skip]
+        let expected = vec![
+            "........",
+            ".###....",
+            ".######.",
+            "..####..",
+            "..##....",
+            "........",
+        ];
+        assert_eq!(expected, render(&[t]));
+    }
+
+    #[test]
+    fn rasterize_reversedVertexOrder_samePixelsCovered() {
+        let t1 = triangle((0.5, 0.5), (7.5, 2.5), (2.5, 5.5));
+        let t2 = triangle((2.5, 5.5), (7.5, 2.5), (0.5, 0.5));
+
+        assert_eq!(render(&[t1]), render(&[t2]));
+    }
+
+    #[test]
+    fn rasterize_squareSplitAlongDiagonal_everyPixelCoveredExactlyOnce() {
+        let t1 = triangle((1f64, 1f64), (7f64, 1f64), (7f64, 5f64));
+        let t2 = triangle((1f64, 1f64), (7f64, 5f64), (1f64, 5f64));
+
+        #[rustfmt::skip]
+        let expected = vec![
+            "........",
+            ".######.",
+            ".######.",
+            ".######.",
+            ".######.",
+            "........",
+        ];
+        assert_eq!(expected, render(&[t1, t2]));
+    }
+
+    #[test]
+    fn rasterize_fanAroundSharedVertex_everyPixelCoveredExactlyOnce() {
+        let center = (3.5, 2.5);
+        let t1 = triangle(center, (0f64, 0f64), (8f64, 0f64));
+        let t2 = triangle(center, (8f64, 0f64), (8f64, 6f64));
+        let t3 = triangle(center, (8f64, 6f64), (0f64, 6f64));
+        let t4 = triangle(center, (0f64, 6f64), (0f64, 0f64));
+
+        let expected = vec!["########"; HEIGHT];
+        assert_eq!(expected, render(&[t1, t2, t3, t4]));
+    }
+
+    #[test]
+    fn rasterize_collinearVertices_noPixelsCovered() {
+        let diagonal = triangle((0.5, 0.5), (3.5, 3.5), (5.5, 5.5));
+        let horizontal = triangle((0.5, 2.5), (3.5, 2.5), (7.5, 2.5));
+
+        let expected = vec!["........"; HEIGHT];
+        assert_eq!(expected, render(&[diagonal]));
+        assert_eq!(expected, render(&[horizontal]));
+    }
+
+    #[test]
+    fn rasterize_thinTriangle_onlyPixelsWithCentersInsideCovered() {
+        let t = triangle((0.5, 0.5), (7.5, 4.5), (7.5, 5.5));
+
+        #[rustfmt::skip]
+        let expected = vec![
+            "........",
+            "........",
+            "...#....",
+            ".....#..",
+            "......#.",
+            "........",
+        ];
+        assert_eq!(expected, render(&[t]));
+    }
+
+    #[test]
+    fn rasterize_triangleExceedingCanvas_clippedToCanvas() {
+        let t = triangle((-4f64, -2f64), (12f64, 3f64), (2f64, 10f64));
+
+        #[rustfmt::skip]
+        let expected = vec![
+            "####....",
+            "#######.",
+            "########",
+            "########",
+            "########",
+            "########",
+        ];
+        assert_eq!(expected, render(&[t]));
+    }
+
+    #[test]
+    fn rasterize_triangleOutsideCanvas_noPixelsCovered() {
+        let t = triangle((-6f64, -1f64), (-1f64, 3f64), (-3f64, 9f64));
+
+        let expected = vec!["........"; HEIGHT];
+        assert_eq!(expected, render(&[t]));
+    }
+}
@@ -1,28 +1,30 @@
 use std::sync::Arc;
 
-use crate::{fitness::FitnessFunction, models::Image, mutators::Mutator, ColorMode};
+use crate::{
+    fitness::FitnessFunction, models::Image, mutators::Mutator, ColorMode, RunConfiguration,
+};
 
 #[derive(Clone)]
 pub(crate) struct JobContext {
     image: Arc<Image>,
-    mutator: Arc<Box<dyn Mutator + Send + Sync>>,
+    mutators: Arc<Vec<Box<dyn Mutator + Send + Sync>>>,
     fitness: Arc<Box<dyn FitnessFunction + Send + Sync>>,
-    color_mode: ColorMode,
+    configuration: Arc<RunConfiguration>,
 }
 
 impl JobContext {
     #[must_use]
     pub fn new(
         image: Image,
-        mutator: Box<dyn Mutator + Send + Sync>,
+        mutators: Vec<Box<dyn Mutator + Send + Sync>>,
         fitness: Box<dyn FitnessFunction + Send + Sync>,
-        color_mode: ColorMode,
+        configuration: RunConfiguration,
     ) -> Self {
         Self {
             image: Arc::new(image),
-            mutator: Arc::new(mutator),
+            mutators: Arc::new(mutators),
             fitness: Arc::new(fitness),
-            color_mode,
+            configuration: Arc::new(configuration),
         }
     }
 
@@ -30,15 +32,19 @@ impl JobContext {
         &self.image
     }
 
-    pub fn get_mutator(&self) -> &Arc<Box<dyn Mutator + Send + Sync>> {
-        &self.mutator
+    pub fn get_mutator(&self, index: usize) -> &(dyn Mutator + Send + Sync) {
+        self.mutators[index].as_ref()
     }
 
     pub fn get_fitness(&self) -> &Arc<Box<dyn FitnessFunction + Send + Sync>> {
         &self.fitness
     }
 
+    pub fn get_configuration(&self) -> &RunConfiguration {
+        &self.configuration
+    }
+
     pub fn get_color_mode(&self) -> ColorMode {
-        self.color_mode
+        self.configuration.get_color_mode()
     }
 }
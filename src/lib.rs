@@ -27,6 +27,8 @@
 //! }
 //! ```
 
+mod adaptive_selection;
+
 mod arg_parser;
 pub use arg_parser::ArgParser;
 
@@ -36,6 +38,9 @@ pub use color_mode::ColorMode;
 mod display;
 pub use display::DisplayCondition;
 
+mod run_configuration;
+pub use run_configuration::RunConfiguration;
+
 mod environment;
 
 mod environment_builder;
@@ -47,9 +52,12 @@ pub mod crossover;
 /// This module contains utilities used to calculate the fitness of specimens.
 pub mod fitness;
 
+mod geometry;
+
 pub(crate) mod job_context;
 
 mod models;
+pub use models::{Brush, ErrorMap, Image, Palette, Pixel};
 
 /// This module contains utilities used to mutate specimens.
 pub mod mutators;
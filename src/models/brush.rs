@@ -0,0 +1,118 @@
+use anyhow::{Error, Result};
+
+use crate::models::{Image, Pixel};
+
+/// A small image pasted onto specimens by [StampMutator], along with a mask which says how much of each of its pixels
+/// is painted.
+///
+/// [StampMutator]: crate::mutators::StampMutator
+///
+/// # Examples
+///
+/// ```
+/// use franklin::{Brush, Image, Pixel};
+///
+/// let image = Image::blank(2, 2, &Pixel::new(255, 0, 0));
+/// let brush = Brush::new(image, vec![255, 128, 128, 0]).unwrap();
+///
+/// assert_eq!(0.0, brush.get_mask(1, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Brush {
+    image: Image,
+    mask: Vec<f64>,
+    luminance_masked: bool,
+}
+
+impl Brush {
+    /// Creates a brush from the given image and its mask, stored row by row, where 0 means that a pixel isn't painted
+    /// at all and 255 means that it's fully painted.
+    ///
+    /// Returns an error if the image is empty or the mask has a different number of pixels than the image.
+    pub fn new(image: Image, mask: Vec<u8>) -> Result<Self> {
+        if image.pixels().is_empty() {
+            return Err(Error::msg("Brush cannot be empty."));
+        }
+
+        if mask.len() != image.pixels().len() {
+            return Err(Error::msg(
+                "Mask of a brush must have the same size as its image.",
+            ));
+        }
+
+        Ok(Self {
+            image,
+            mask: mask
+                .iter()
+                .map(|&alpha| f64::from(alpha) / 255f64)
+                .collect(),
+            luminance_masked: false,
+        })
+    }
+
+    /// Creates a brush from an image without transparency, which is painted where the image is dark, e.g. ink on
+    /// white paper. Tinted stamps of such brushes are painted entirely with the color of the tint.
+    ///
+    /// Returns an error if the image is empty.
+    pub fn from_luminance(image: Image) -> Result<Self> {
+        let mask = image
+            .pixels()
+            .iter()
+            .map(|pixel| 255 - pixel.luminance().round().clamp(0f64, 255f64) as u8)
+            .collect();
+
+        let mut brush = Self::new(image, mask)?;
+        brush.luminance_masked = true;
+
+        Ok(brush)
+    }
+
+    /// Returns the width of the brush in pixels.
+    pub fn width(&self) -> usize {
+        self.image.width()
+    }
+
+    /// Returns the height of the brush in pixels.
+    pub fn height(&self) -> usize {
+        self.image.height()
+    }
+
+    /// Returns the color of the pixel in column `x` and row `y`.
+    pub fn get_color(&self, x: usize, y: usize) -> &Pixel {
+        &self.image[y * self.image.width() + x]
+    }
+
+    /// Returns how much of the pixel in column `x` and row `y` is painted, in range `[0, 1]`.
+    pub fn get_mask(&self, x: usize, y: usize) -> f64 {
+        self.mask[y * self.image.width() + x]
+    }
+
+    /// Returns whether the mask of the brush is derived from the luminance of its image, see [Brush::from_luminance].
+    pub fn is_luminance_masked(&self) -> bool {
+        self.luminance_masked
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_maskOfDifferentSize_errorReturned() {
+        let image = Image::blank(2, 2, &Pixel::white());
+
+        assert!(Brush::new(image, vec![255; 3]).is_err());
+    }
+
+    #[test]
+    fn fromLuminance_blackAndWhitePixels_darkPixelsPainted() {
+        let image = Image::new(1, 2, vec![Pixel::grayscale(0), Pixel::white()]);
+
+        let brush = Brush::from_luminance(image).unwrap();
+
+        assert_eq!(1f64, brush.get_mask(0, 0));
+        assert_eq!(0f64, brush.get_mask(1, 0));
+        assert!(brush.is_luminance_masked());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    models::{Image, Pixel},
+    util::Random,
+    ColorMode,
+};
+
+fn get_error(first: &Pixel, second: &Pixel, color_mode: ColorMode) -> f64 {
+    let difference = |first: u8, second: u8| (f64::from(first) - f64::from(second)).powi(2);
+
+    match color_mode {
+        ColorMode::Rgb => {
+            difference(first.get_r(), second.get_r())
+                + difference(first.get_g(), second.get_g())
+                + difference(first.get_b(), second.get_b())
+        }
+        ColorMode::Grayscale => 3f64 * difference(first.get_r(), second.get_r()),
+    }
+}
+
+/// Per-pixel error between a specimen and the target image, i.e. the square distance between colors of respective
+/// pixels.
+///
+/// Errors are stored row by row, the same way as pixels of an [Image].
+#[derive(Debug, Clone)]
+pub struct ErrorMap {
+    width: usize,
+    height: usize,
+    errors: Vec<f64>,
+    cumulative: Vec<f64>,
+    total: f64,
+}
+
+impl ErrorMap {
+    /// Calculates the error map of `specimen` with respect to `target`.
+    ///
+    /// In grayscale mode only the first color channel is compared.
+    #[must_use]
+    pub fn new(target: &Image, specimen: &Image, color_mode: ColorMode) -> Self {
+        assert_eq!(
+            (target.width(), target.height()),
+            (specimen.width(), specimen.height()),
+            "Both images must have the same dimensions."
+        );
+
+        let errors = target
+            .pixels()
+            .iter()
+            .zip(specimen.pixels())
+            .map(|(first, second)| get_error(first, second, color_mode))
+            .collect::<Vec<f64>>();
+
+        let cumulative = errors
+            .iter()
+            .scan(0f64, |sum, error| {
+                *sum += error;
+                Some(*sum)
+            })
+            .collect::<Vec<f64>>();
+
+        Self {
+            width: target.width(),
+            height: target.height(),
+            total: cumulative.last().copied().unwrap_or_default(),
+            errors,
+            cumulative,
+        }
+    }
+
+    /// Returns the width of the map in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the map in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the error of the pixel in column `x` and row `y`.
+    pub fn get_error(&self, x: usize, y: usize) -> f64 {
+        self.errors[y * self.width + x]
+    }
+
+    /// Returns the sum of errors of all pixels.
+    pub fn get_total(&self) -> f64 {
+        self.total
+    }
+
+    /// Returns coordinates `(x, y)` of a random pixel, picked with probability proportional to its error.
+    ///
+    /// Returns `None` if there's no error at all, i.e. the specimen matches the target.
+    pub(crate) fn get_random_pixel(&self, random: &mut Random) -> Option<(usize, usize)> {
+        if self.total <= 0f64 {
+            return None;
+        }
+
+        let threshold = random.get_random(0f64, self.total);
+        let index = self
+            .cumulative
+            .partition_point(|&sum| sum <= threshold)
+            .min(self.cumulative.len() - 1);
+
+        Some((index % self.width, index / self.width))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_differentPixels_squareDistancesCalculated() {
+        let target = Image::new(1, 2, vec![Pixel::new(10, 20, 30), Pixel::grayscale(0)]);
+        let specimen = Image::new(1, 2, vec![Pixel::new(13, 16, 30), Pixel::grayscale(0)]);
+
+        let error_map = ErrorMap::new(&target, &specimen, ColorMode::Rgb);
+
+        assert_eq!(25f64, error_map.get_error(0, 0));
+        assert_eq!(0f64, error_map.get_error(1, 0));
+        assert_eq!(25f64, error_map.get_total());
+    }
+
+    #[test]
+    fn new_grayscaleMode_onlyFirstChannelCompared() {
+        let target = Image::new(1, 1, vec![Pixel::new(10, 0, 0)]);
+        let specimen = Image::new(1, 1, vec![Pixel::new(12, 100, 100)]);
+
+        let error_map = ErrorMap::new(&target, &specimen, ColorMode::Grayscale);
+
+        assert_eq!(12f64, error_map.get_error(0, 0));
+    }
+
+    #[test]
+    fn getRandomPixel_errorInSinglePixel_onlyThatPixelPicked() {
+        let target = Image::blank(3, 3, &Pixel::white());
+        let mut specimen = Image::blank(3, 3, &Pixel::white());
+        specimen[5] = Pixel::grayscale(0);
+        let error_map = ErrorMap::new(&target, &specimen, ColorMode::Rgb);
+        let mut random = Random::default();
+
+        assert!((0..100).all(|_| error_map.get_random_pixel(&mut random) == Some((2, 1))));
+    }
+
+    #[test]
+    fn getRandomPixel_noError_nonePicked() {
+        let image = Image::blank(3, 3, &Pixel::white());
+        let error_map = ErrorMap::new(&image, &image, ColorMode::Rgb);
+
+        assert_eq!(None, error_map.get_random_pixel(&mut Random::default()));
+    }
+
+    #[test]
+    fn getRandomPixel_differentErrors_pixelsPickedProportionally() {
+        let target = Image::new(1, 2, vec![Pixel::grayscale(0), Pixel::grayscale(0)]);
+        let specimen = Image::new(1, 2, vec![Pixel::grayscale(10), Pixel::grayscale(20)]);
+        let error_map = ErrorMap::new(&target, &specimen, ColorMode::Rgb);
+        let mut random = Random::default();
+
+        let first = (0..5000)
+            .filter(|_| error_map.get_random_pixel(&mut random) == Some((0, 0)))
+            .count();
+
+        assert!((800..1200).contains(&first));
+    }
+}
@@ -2,6 +2,9 @@ use std::ops::{Index, IndexMut};
 
 use crate::models::pixel::Pixel;
 
+/// An RGB image, i.e. a single specimen or the source image.
+///
+/// Pixels are stored row by row, so the pixel in column `x` and row `y` can be accessed with `image[y * width + x]`.
 #[derive(Debug, Clone)]
 pub struct Image {
     height: usize,
@@ -10,6 +13,7 @@ pub struct Image {
 }
 
 impl Image {
+    /// Creates a new image from the given pixels, stored row by row.
     #[must_use]
     pub fn new(height: usize, width: usize, pixels: Vec<Pixel>) -> Self {
         Self {
@@ -19,6 +23,7 @@ impl Image {
         }
     }
 
+    /// Creates a new image with all pixels set to `pixel`.
     #[must_use]
     pub fn blank(height: usize, width: usize, pixel: &Pixel) -> Self {
         let size = height * width;
@@ -27,18 +32,22 @@ impl Image {
         Self::new(height, width, pixels)
     }
 
+    /// Returns the width of the image in pixels.
     pub fn width(&self) -> usize {
         self.width
     }
 
+    /// Returns the height of the image in pixels.
     pub fn height(&self) -> usize {
         self.height
     }
 
+    /// Returns all pixels of the image, stored row by row.
     pub fn pixels(&self) -> &Vec<Pixel> {
         &self.pixels
     }
 
+    /// Returns the RGB channels of all pixels, stored row by row.
     #[must_use]
     pub fn as_raw_bytes(&self) -> Vec<u8> {
         self.pixels.iter().fold(
@@ -1,5 +1,14 @@
 mod image;
-pub(crate) use self::image::Image;
+pub use self::image::Image;
 
 mod pixel;
-pub(crate) use self::pixel::Pixel;
+pub use self::pixel::Pixel;
+
+mod palette;
+pub use self::palette::Palette;
+
+mod brush;
+pub use self::brush::Brush;
+
+mod error_map;
+pub use self::error_map::ErrorMap;
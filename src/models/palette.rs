@@ -0,0 +1,374 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{Error, Result};
+
+use crate::{
+    models::{Image, Pixel},
+    util::Random,
+};
+
+/// Maximum number of pixels of the source image used to extract a palette.
+const MAX_SAMPLES: usize = 10_000;
+
+/// Maximum number of iterations of k-means clustering.
+const MAX_ITERATIONS: usize = 50;
+
+const GPL_HEADER: &str = "GIMP Palette";
+
+fn get_channels(pixel: &Pixel) -> [f64; 3] {
+    [
+        f64::from(pixel.get_r()),
+        f64::from(pixel.get_g()),
+        f64::from(pixel.get_b()),
+    ]
+}
+
+fn square_distance(first: &[f64; 3], second: &[f64; 3]) -> f64 {
+    first
+        .iter()
+        .zip(second)
+        .map(|(first, second)| (first - second).powi(2))
+        .sum()
+}
+
+fn get_nearest(centroids: &[[f64; 3]], sample: &[f64; 3]) -> (usize, f64) {
+    centroids
+        .iter()
+        .map(|centroid| square_distance(centroid, sample))
+        .enumerate()
+        .min_by(|(_, first), (_, second)| first.total_cmp(second))
+        // Safety: it's safe to unwrap because there is always at least one centroid.
+        .unwrap()
+}
+
+fn parse_hex(code: &str) -> Result<Pixel> {
+    let hex = code.strip_prefix('#').unwrap_or(code);
+
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(Error::msg(format!("Invalid color code: {}.", code)));
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16);
+
+    Ok(Pixel::new(channel(0)?, channel(2)?, channel(4)?))
+}
+
+fn parse_gpl(s: &str) -> Result<Vec<Pixel>> {
+    s.trim_start()
+        .lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|line| {
+            !line.is_empty()
+                && !line.starts_with('#')
+                && !line.starts_with("Name:")
+                && !line.starts_with("Columns:")
+        })
+        .map(|line| {
+            let channels = line
+                .split_whitespace()
+                .take(3)
+                .map(str::parse::<u8>)
+                .collect::<Result<Vec<u8>, _>>()?;
+
+            match channels[..] {
+                [r, g, b] => Ok(Pixel::new(r, g, b)),
+                _ => Err(Error::msg(format!("Invalid palette entry: {}.", line))),
+            }
+        })
+        .collect()
+}
+
+/// A fixed set of colors which mutators are allowed to paint with.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::Palette;
+///
+/// let palette = "#000000 #ff0000 #ffffff".parse::<Palette>().unwrap();
+///
+/// assert_eq!(3, palette.get_colors().len());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colors: Vec<Pixel>,
+}
+
+impl Palette {
+    /// Creates a palette consisting of the given colors.
+    ///
+    /// Returns an error if `colors` is empty.
+    pub fn new(colors: Vec<Pixel>) -> Result<Self> {
+        if colors.is_empty() {
+            return Err(Error::msg("Palette must have at least one color."));
+        }
+
+        Ok(Self { colors })
+    }
+
+    /// Extracts a palette of at most `size` colors from the given image, using k-means clustering.
+    ///
+    /// The palette can be smaller than `size` if the image has fewer distinct colors. Extractions with the same `seed`
+    /// give the same palette. Without a seed the clustering is seeded from entropy. Returns an error if `size` is zero
+    /// or the image is empty.
+    pub fn extract(image: &Image, size: usize, seed: Option<u64>) -> Result<Self> {
+        if size == 0 {
+            return Err(Error::msg("Palette size cannot be zero."));
+        }
+
+        let pixels = image.pixels();
+        if pixels.is_empty() {
+            return Err(Error::msg("Cannot extract a palette from an empty image."));
+        }
+
+        let samples = pixels
+            .iter()
+            .step_by(pixels.len() / MAX_SAMPLES + 1)
+            .map(get_channels)
+            .collect::<Vec<[f64; 3]>>();
+
+        let mut random = seed.map_or_else(Random::default, Random::from_seed);
+
+        // k-means++ initialization: every next centroid is picked with probability proportional to the square
+        // distance to the nearest centroid picked so far.
+        let mut centroids = vec![samples[random.get_random(0, samples.len())]];
+        while centroids.len() < size {
+            let distances = samples
+                .iter()
+                .map(|sample| get_nearest(&centroids, sample).1)
+                .collect::<Vec<f64>>();
+
+            let total = distances.iter().sum::<f64>();
+            if total <= 0f64 {
+                break;
+            }
+
+            let mut threshold = random.get_random(0f64, total);
+            let index = distances
+                .iter()
+                .position(|distance| {
+                    threshold -= distance;
+                    threshold < 0f64
+                })
+                .unwrap_or(samples.len() - 1);
+
+            centroids.push(samples[index]);
+        }
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut sums = vec![[0f64; 3]; centroids.len()];
+            let mut counts = vec![0usize; centroids.len()];
+
+            for sample in &samples {
+                let (nearest, _) = get_nearest(&centroids, sample);
+
+                for channel in 0..3 {
+                    sums[nearest][channel] += sample[channel];
+                }
+                counts[nearest] += 1;
+            }
+
+            let mut moved = false;
+            for (index, centroid) in centroids.iter_mut().enumerate() {
+                if counts[index] > 0 {
+                    let mean = sums[index].map(|sum| sum / counts[index] as f64);
+                    moved |= square_distance(centroid, &mean) > 0.25;
+                    *centroid = mean;
+                }
+            }
+
+            if !moved {
+                break;
+            }
+        }
+
+        let colors = centroids
+            .iter()
+            .map(|centroid| {
+                let [r, g, b] = centroid.map(|channel| channel.round().clamp(0f64, 255f64) as u8);
+                Pixel::new(r, g, b)
+            })
+            .collect();
+
+        Self::new(colors)
+    }
+
+    /// Returns colors of the palette.
+    pub fn get_colors(&self) -> &[Pixel] {
+        &self.colors
+    }
+
+    /// Returns the color of the palette which is the closest to `color`.
+    pub fn get_closest(&self, color: &Pixel) -> &Pixel {
+        let color = get_channels(color);
+
+        self.colors
+            .iter()
+            .min_by(|first, second| {
+                square_distance(&get_channels(first), &color)
+                    .total_cmp(&square_distance(&get_channels(second), &color))
+            })
+            // Safety: it's safe to unwrap because palette always has at least one color.
+            .unwrap()
+    }
+
+    /// Returns a copy of this palette with all colors converted to shades of gray of the same luminance.
+    #[must_use]
+    pub fn to_grayscale(&self) -> Self {
+        Self {
+            colors: self
+                .colors
+                .iter()
+                .map(|color| Pixel::grayscale(color.luminance().round() as u8))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Palette {
+    type Err = Error;
+
+    /// Parses either a GIMP palette (GPL) or a list of hex color codes (e.g. "#ff8800"), separated by whitespace.
+    fn from_str(s: &str) -> Result<Self> {
+        let colors = if s.trim_start().starts_with(GPL_HEADER) {
+            parse_gpl(s)?
+        } else {
+            s.split_whitespace()
+                .map(parse_hex)
+                .collect::<Result<Vec<Pixel>>>()?
+        };
+
+        Self::new(colors)
+    }
+}
+
+impl fmt::Display for Palette {
+    /// Formats the palette as a GIMP palette (GPL).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", GPL_HEADER)?;
+        writeln!(f, "Name: franklin")?;
+        writeln!(f, "#")?;
+
+        for color in &self.colors {
+            writeln!(
+                f,
+                "{:>3} {:>3} {:>3}\t#{:02x}{:02x}{:02x}",
+                color.get_r(),
+                color.get_g(),
+                color.get_b(),
+                color.get_r(),
+                color.get_g(),
+                color.get_b()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fromStr_hexCodes_colorsParsed() {
+        let palette = "#000000\nff8800  #FFFFFF".parse::<Palette>().unwrap();
+
+        assert_eq!(
+            vec![
+                Pixel::new(0, 0, 0),
+                Pixel::new(255, 136, 0),
+                Pixel::new(255, 255, 255)
+            ],
+            palette.get_colors()
+        );
+    }
+
+    #[test]
+    fn fromStr_gimpPalette_colorsParsed() {
+        let palette =
+            "GIMP Palette\nName: Inks\nColumns: 2\n#\n  0  0  0\tBlack\n255 128 0 Orange\n"
+                .parse::<Palette>()
+                .unwrap();
+
+        assert_eq!(
+            vec![Pixel::new(0, 0, 0), Pixel::new(255, 128, 0)],
+            palette.get_colors()
+        );
+    }
+
+    #[test]
+    fn fromStr_invalidHexCode_errorReturned() {
+        assert!("#12345".parse::<Palette>().is_err());
+        assert!("#gg0000".parse::<Palette>().is_err());
+        assert!("".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn toString_anyPalette_canBeParsedBack() {
+        let palette = Palette::new(vec![Pixel::new(1, 2, 3), Pixel::new(250, 128, 0)]).unwrap();
+
+        assert_eq!(palette, palette.to_string().parse::<Palette>().unwrap());
+    }
+
+    #[test]
+    fn getClosest_anyColor_nearestColorReturned() {
+        let palette = Palette::new(vec![Pixel::new(0, 0, 0), Pixel::new(255, 0, 0)]).unwrap();
+
+        assert_eq!(
+            &Pixel::new(255, 0, 0),
+            palette.get_closest(&Pixel::new(200, 50, 50))
+        );
+        assert_eq!(
+            &Pixel::new(0, 0, 0),
+            palette.get_closest(&Pixel::new(100, 10, 10))
+        );
+    }
+
+    #[test]
+    fn extract_twoColorImage_bothColorsExtracted() {
+        let mut pixels = vec![Pixel::new(10, 20, 30); 50];
+        pixels.extend(vec![Pixel::new(200, 150, 100); 50]);
+        let image = Image::new(10, 10, pixels);
+
+        let palette = Palette::extract(&image, 2, None).unwrap();
+
+        let mut colors = palette.get_colors().to_vec();
+        colors.sort_by_key(Pixel::as_slice);
+        assert_eq!(
+            vec![Pixel::new(10, 20, 30), Pixel::new(200, 150, 100)],
+            colors
+        );
+    }
+
+    #[test]
+    fn extract_moreColorsThanImageHas_paletteSmallerThanRequested() {
+        let image = Image::blank(4, 4, &Pixel::new(1, 2, 3));
+
+        let palette = Palette::extract(&image, 5, None).unwrap();
+
+        assert_eq!(vec![Pixel::new(1, 2, 3)], palette.get_colors());
+    }
+
+    #[test]
+    fn extract_sameSeeds_samePalettesExtracted() {
+        let pixels = (0..400)
+            .map(|index| Pixel::new((index % 20 * 13) as u8, (index / 20 * 13) as u8, 90))
+            .collect();
+        let image = Image::new(20, 20, pixels);
+
+        let first = Palette::extract(&image, 4, Some(5)).unwrap();
+        let second = Palette::extract(&image, 4, Some(5)).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn extract_zeroSize_errorReturned() {
+        let image = Image::blank(4, 4, &Pixel::white());
+
+        assert!(Palette::extract(&image, 0, None).is_err());
+    }
+}
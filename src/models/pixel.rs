@@ -1,3 +1,6 @@
+/// A single pixel of an [Image], consisting of three color channels.
+///
+/// [Image]: crate::Image
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Pixel {
     r: u8,
@@ -6,57 +9,91 @@ pub struct Pixel {
 }
 
 impl Pixel {
+    /// Creates a white pixel.
     #[must_use]
     pub const fn white() -> Self {
         Pixel::new(255, 255, 255)
     }
 
+    /// Creates a gray pixel, with all channels set to `grayscale`.
     #[must_use]
     pub const fn grayscale(grayscale: u8) -> Self {
         Pixel::new(grayscale, grayscale, grayscale)
     }
 
+    /// Creates a pixel with the given color channels.
     #[must_use]
     pub const fn new(r: u8, g: u8, b: u8) -> Self {
         Pixel { r, g, b }
     }
 
+    /// Sets the red channel.
     pub fn r(&mut self, r: u8) {
         self.r = r;
     }
 
+    /// Sets the green channel.
     pub fn g(&mut self, g: u8) {
         self.g = g;
     }
 
+    /// Sets the blue channel.
     pub fn b(&mut self, b: u8) {
         self.b = b;
     }
 
+    /// Returns the red channel.
     pub fn get_r(&self) -> u8 {
         self.r
     }
 
+    /// Returns the green channel.
     pub fn get_g(&self) -> u8 {
         self.g
     }
 
+    /// Returns the blue channel.
     pub fn get_b(&self) -> u8 {
         self.b
     }
 
+    /// Sets all channels to `grayscale`.
     pub fn set_grayscale(&mut self, grayscale: u8) {
         self.r(grayscale);
         self.g(grayscale);
         self.b(grayscale);
     }
 
+    /// Returns the perceived brightness of the pixel in range `[0, 255]`.
+    pub fn luminance(&self) -> f64 {
+        0.299 * f64::from(self.r) + 0.587 * f64::from(self.g) + 0.114 * f64::from(self.b)
+    }
+
+    /// Composites `color` over this pixel.
+    ///
+    /// `opacity` must be in range `[0, 1]`, where 0 leaves the pixel untouched and 1 replaces it with `color`.
+    pub fn blend(&mut self, color: &Pixel, opacity: f64) {
+        self.r = mix_channel(self.r, color.r, opacity);
+        self.g = mix_channel(self.g, color.g, opacity);
+        self.b = mix_channel(self.b, color.b, opacity);
+    }
+
+    /// Returns the channels in RGB order.
     #[must_use]
     pub fn as_slice(&self) -> [u8; 3] {
         [self.r, self.g, self.b]
     }
 }
 
+fn mix_channel(destination: u8, source: u8, opacity: f64) -> u8 {
+    let destination = f64::from(destination);
+    let source = f64::from(source);
+
+    (destination + (source - destination) * opacity)
+        .round()
+        .clamp(0f64, 255f64) as u8
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod test {
@@ -127,6 +164,50 @@ mod test {
         assert_eq!(128, pixel.get_b());
     }
 
+    #[test]
+    fn luminance_grayscalePixel_returnsGrayscaleValue() {
+        let pixel = Pixel::grayscale(100);
+
+        assert!((pixel.luminance() - 100f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn blend_fullOpacity_pixelReplacedWithColor() {
+        let mut pixel = Pixel::new(10, 20, 30);
+
+        pixel.blend(&Pixel::new(200, 100, 0), 1f64);
+
+        assert_eq!(Pixel::new(200, 100, 0), pixel);
+    }
+
+    #[test]
+    fn blend_zeroOpacity_pixelNotChanged() {
+        let mut pixel = Pixel::new(10, 20, 30);
+
+        pixel.blend(&Pixel::new(200, 100, 0), 0f64);
+
+        assert_eq!(Pixel::new(10, 20, 30), pixel);
+    }
+
+    #[test]
+    fn blend_halfOpacity_channelsSetToMidpoint() {
+        let mut pixel = Pixel::new(0, 100, 255);
+
+        pixel.blend(&Pixel::new(200, 0, 55), 0.5);
+
+        assert_eq!(Pixel::new(100, 50, 155), pixel);
+    }
+
+    #[test]
+    fn blend_grayscalePixels_resultStaysGrayscale() {
+        let mut pixel = Pixel::grayscale(30);
+
+        pixel.blend(&Pixel::grayscale(171), 0.3);
+
+        assert_eq!(pixel.get_r(), pixel.get_g());
+        assert_eq!(pixel.get_r(), pixel.get_b());
+    }
+
     #[test]
     fn as_slice_fieldsWithDifferentValues_returnsSliceWithCorrectValues() {
         let pixel = Pixel::new(125, 126, 127);
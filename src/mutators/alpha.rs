@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+
+use crate::util::Random;
+
+/// This enum specifies how the alpha value of shapes generated by mutators is chosen.
+///
+/// Alpha value of 0 means that the shape is fully transparent, while alpha value of 255 means that the shape is fully
+/// opaque.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alpha {
+    /// Every shape is drawn with the same alpha value.
+    Fixed(u8),
+
+    /// Every shape is drawn with an alpha value picked at random from the inclusive range `min..=max`.
+    ///
+    /// `min` cannot be greater than `max`, see [Alpha::random].
+    Random {
+        /// The lowest alpha value which can be picked.
+        min: u8,
+
+        /// The highest alpha value which can be picked.
+        max: u8,
+    },
+}
+
+impl Alpha {
+    /// Creates an alpha value picked at random from the inclusive range `min..=max` for every shape.
+    ///
+    /// This method returns an error if `min` is greater than `max`.
+    pub fn random(min: u8, max: u8) -> Result<Self> {
+        if min > max {
+            return Err(Error::msg(
+                "The lower bound of alpha cannot be greater than the upper bound.",
+            ));
+        }
+
+        Ok(Alpha::Random { min, max })
+    }
+
+    /// Returns opacity of a new shape in range `[0, 1]`.
+    pub(crate) fn get_opacity(&self, random: &mut Random) -> f64 {
+        let alpha = match *self {
+            Alpha::Fixed(alpha) => alpha,
+            Alpha::Random { min, max } => {
+                random.get_random(u16::from(min), u16::from(max) + 1) as u8
+            }
+        };
+
+        f64::from(alpha) / 255f64
+    }
+}
+
+impl Default for Alpha {
+    fn default() -> Self {
+        Alpha::Fixed(u8::MAX)
+    }
+}
+
+impl FromStr for Alpha {
+    type Err = Error;
+
+    /// Parses either a single value (e.g. "128") or an inclusive range (e.g. "64-192").
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once('-') {
+            Some((min, max)) => {
+                let min = min.trim().parse::<u8>()?;
+                let max = max.trim().parse::<u8>()?;
+
+                Alpha::random(min, max)
+            }
+            None => Ok(Alpha::Fixed(s.trim().parse::<u8>()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn random_minGreaterThanMax_errorReturned() {
+        assert!(Alpha::random(192, 64).is_err());
+    }
+
+    #[test]
+    fn getOpacity_randomAlpha_opacityWithinRange() {
+        let alpha = Alpha::random(64, 192).unwrap();
+        let mut random = Random::default();
+
+        for _ in 0..1000 {
+            let opacity = alpha.get_opacity(&mut random);
+
+            assert!((64f64 / 255f64..=192f64 / 255f64).contains(&opacity));
+        }
+    }
+
+    #[test]
+    fn fromStr_rangeGiven_randomAlphaParsed() {
+        assert_eq!(
+            Alpha::Random { min: 64, max: 192 },
+            "64-192".parse().unwrap()
+        );
+        assert!("192-64".parse::<Alpha>().is_err());
+    }
+}
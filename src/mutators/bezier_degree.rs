@@ -0,0 +1,11 @@
+/// This enum specifies the degree of curves generated by [BezierMutator].
+///
+/// [BezierMutator]: crate::mutators::BezierMutator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BezierDegree {
+    /// Generates quadratic curves, with 3 control points.
+    Quadratic,
+
+    /// Generates cubic curves, with 4 control points.
+    Cubic,
+}
@@ -0,0 +1,265 @@
+use anyhow::{Error, Result};
+
+use crate::{
+    geometry::{Bezier, BezierPath, Point},
+    models::Image,
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::{BezierDegree, BezierShape, MutationContext, Mutator, ShapeStyle};
+
+/// This mutator mutates the given specimen by generating a random Bézier curve with random color.
+///
+/// Curves are either stroked, with the width changing linearly from one end to the other, or filled. Filled shapes
+/// are closed paths made of two curves. By default the mutator generates stroked cubic curves, 1 to 10 pixels wide.
+///
+/// Control points of drawn curves can be kept for vector output, by mutating specimens with
+/// [BezierMutator::mutate_with_shape] instead of [Mutator::mutate].
+#[derive(Debug)]
+pub struct BezierMutator {
+    style: ShapeStyle,
+    degree: BezierDegree,
+    filled: bool,
+    min_width: usize,
+    max_width: usize,
+}
+
+impl BezierMutator {
+    /// Creates a new mutator which paints curves with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the degree of generated curves.
+    ///
+    /// This property is optional. The default value is: [BezierDegree::Cubic].
+    pub fn set_degree(&mut self, degree: BezierDegree) {
+        self.degree = degree;
+    }
+
+    /// Sets whether generated curves are filled closed shapes, instead of stroked paths.
+    ///
+    /// This property is optional. The default value is: `false`.
+    pub fn set_filled(&mut self, filled: bool) {
+        self.filled = filled;
+    }
+
+    /// Sets the inclusive range from which the width at both ends of each stroked curve, in pixels, is picked.
+    ///
+    /// This method returns an error if `min_width` is zero or it's greater than `max_width`. The width has no effect
+    /// on filled curves.
+    ///
+    /// This property is optional. The default values are: 1 for `min_width` and 10 for `max_width`.
+    pub fn set_width(&mut self, min_width: usize, max_width: usize) -> Result<()> {
+        if min_width == 0 {
+            return Err(Error::msg("Curve width cannot be zero."));
+        }
+
+        if min_width > max_width {
+            return Err(Error::msg(
+                "Minimal width cannot be greater than the maximal width.",
+            ));
+        }
+
+        self.min_width = min_width;
+        self.max_width = max_width;
+
+        Ok(())
+    }
+
+    /// Mutates the given image like [Mutator::mutate] and returns control points of the drawn shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::{
+    ///     mutators::{BezierMutator, MutationContext},
+    ///     ColorMode, Image, Pixel, RunConfiguration,
+    /// };
+    ///
+    /// let mutator = BezierMutator::default();
+    /// let configuration = RunConfiguration::new(ColorMode::Rgb);
+    /// let mut image = Image::blank(20, 20, &Pixel::white());
+    ///
+    /// let shape = mutator.mutate_with_shape(&mut image, &mut MutationContext::new(&configuration));
+    /// assert_eq!(4, shape.get_curves()[0].len());
+    /// ```
+    pub fn mutate_with_shape(
+        &self,
+        image: &mut Image,
+        context: &mut MutationContext,
+    ) -> BezierShape {
+        let max_size = self.style.get_max_size(context);
+        let position = self.style.get_random_position(context, image);
+        let path = self.get_random_path(context.get_random(), image, position, max_size);
+
+        self.style.draw(&path, image, context);
+
+        BezierShape::from(&path)
+    }
+
+    fn get_random_curve(
+        &self,
+        random: &mut Random,
+        start: Point,
+        end: Point,
+        center: &Point,
+        radius: f64,
+    ) -> Bezier {
+        let inner_points = match self.degree {
+            BezierDegree::Quadratic => 1,
+            BezierDegree::Cubic => 2,
+        };
+
+        let mut control_points = vec![start];
+        control_points.extend((0..inner_points).map(|_| {
+            Point::new(
+                random.get_random(center.x - radius, center.x + radius),
+                random.get_random(center.y - radius, center.y + radius),
+            )
+        }));
+        control_points.push(end);
+
+        Bezier::new(control_points)
+    }
+
+    fn get_random_path(
+        &self,
+        random: &mut Random,
+        image: &Image,
+        position: Point,
+        max_size: f64,
+    ) -> BezierPath {
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+
+        let center = position;
+        let radius = random.get_random(
+            1f64,
+            (image_width.min(image_height) / 2f64 * max_size).max(2f64),
+        );
+
+        let mut get_random_point = || {
+            Point::new(
+                random.get_random(center.x - radius, center.x + radius),
+                random.get_random(center.y - radius, center.y + radius),
+            )
+        };
+        let start = get_random_point();
+        let end = get_random_point();
+
+        if self.filled {
+            let there = self.get_random_curve(random, start, end, &center, radius);
+            let back = self.get_random_curve(random, end, start, &center, radius);
+
+            BezierPath::filled(vec![there, back])
+        } else {
+            let curve = self.get_random_curve(random, start, end, &center, radius);
+
+            let start_width = random.get_random(self.min_width, self.max_width + 1) as f64;
+            let end_width = random.get_random(self.min_width, self.max_width + 1) as f64;
+
+            BezierPath::stroked(vec![curve], start_width, end_width)
+        }
+    }
+}
+
+impl Default for BezierMutator {
+    fn default() -> Self {
+        Self {
+            style: ShapeStyle::default(),
+            degree: BezierDegree::Cubic,
+            filled: false,
+            min_width: 1,
+            max_width: 10,
+        }
+    }
+}
+
+impl Mutator for BezierMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        self.mutate_with_shape(image, context);
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::models::Pixel;
+
+    #[test]
+    fn mutateWithShape_strokedCurve_changedPixelsWithinControlPoints() {
+        let mutator = BezierMutator::default();
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+
+        for _ in 0..20 {
+            let mut image = Image::blank(30, 30, &Pixel::white());
+            let shape = mutator.mutate_with_shape(&mut image, &mut context);
+
+            assert!(!shape.is_filled());
+            assert_eq!(1, shape.get_curves().len());
+            assert_eq!(4, shape.get_curves()[0].len());
+
+            // A curve lies within the convex hull of its control points, expanded by half of its width.
+            let (start_width, end_width) = shape.get_widths().unwrap();
+            let margin = start_width.max(end_width) / 2f64 + 1f64;
+            let points = &shape.get_curves()[0];
+            let min_x = points.iter().map(|p| p.0).fold(f64::INFINITY, f64::min) - margin;
+            let max_x = points.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max) + margin;
+            let min_y = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min) - margin;
+            let max_y = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max) + margin;
+
+            for (index, pixel) in image.pixels().iter().enumerate() {
+                if *pixel != Pixel::white() {
+                    let (x, y) = ((index % 30) as f64, (index / 30) as f64);
+                    assert!((min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mutateWithShape_filledShape_closedPathOfTwoCurvesReturned() {
+        let mut mutator = BezierMutator::default();
+        mutator.set_filled(true);
+        mutator.set_degree(BezierDegree::Quadratic);
+        let configuration = RunConfiguration::default();
+        let mut image = Image::blank(30, 30, &Pixel::white());
+
+        let shape =
+            mutator.mutate_with_shape(&mut image, &mut MutationContext::new(&configuration));
+
+        let curves = shape.get_curves();
+        assert!(shape.is_filled());
+        assert_eq!(None, shape.get_widths());
+        assert_eq!(2, curves.len());
+        assert_eq!(3, curves[0].len());
+        assert_eq!(curves[0][2], curves[1][0]);
+        assert_eq!(curves[1][2], curves[0][0]);
+    }
+}
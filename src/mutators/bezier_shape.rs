@@ -0,0 +1,49 @@
+use crate::geometry::BezierPath;
+
+/// Control points of a shape drawn by [BezierMutator], kept so that the shape can be exported as vector graphics.
+///
+/// [BezierMutator]: crate::mutators::BezierMutator
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierShape {
+    curves: Vec<Vec<(f64, f64)>>,
+    widths: Option<(f64, f64)>,
+}
+
+impl BezierShape {
+    /// Returns control points of all curves of the shape as `(x, y)` pairs in pixel coordinates.
+    ///
+    /// Every curve starts with its start point and ends with its end point, which is also the start point of the
+    /// next curve. Filled shapes are closed, so the last curve ends at the start point of the first one.
+    pub fn get_curves(&self) -> &[Vec<(f64, f64)>] {
+        &self.curves
+    }
+
+    /// Returns `true` if the shape is filled, instead of stroked.
+    pub fn is_filled(&self) -> bool {
+        self.widths.is_none()
+    }
+
+    /// Returns widths in pixels at the start and at the end of a stroked shape, or `None` if the shape is filled.
+    pub fn get_widths(&self) -> Option<(f64, f64)> {
+        self.widths
+    }
+}
+
+impl From<&BezierPath> for BezierShape {
+    fn from(path: &BezierPath) -> Self {
+        Self {
+            curves: path
+                .curves()
+                .iter()
+                .map(|curve| {
+                    curve
+                        .control_points()
+                        .iter()
+                        .map(|point| (point.x, point.y))
+                        .collect()
+                })
+                .collect(),
+            widths: path.widths(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{models::Pixel, util::Random};
+
+/// Concrete modes picked by [BlendMode::Random].
+const MODES: [BlendMode; 6] = [
+    BlendMode::Normal,
+    BlendMode::Multiply,
+    BlendMode::Screen,
+    BlendMode::Add,
+    BlendMode::Subtract,
+    BlendMode::Difference,
+];
+
+/// This enum specifies how colors of shapes are combined with colors of the specimen underneath them.
+///
+/// Every mode combines the two colors channel by channel. The result is then blended with the original color according
+/// to the opacity of the shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The color of the shape replaces the color underneath it.
+    #[default]
+    Normal,
+
+    /// Multiplies both colors, which darkens the specimen.
+    Multiply,
+
+    /// Multiplies inverted colors and inverts the result, which lightens the specimen.
+    Screen,
+
+    /// Adds both colors, clipping channels at 255.
+    Add,
+
+    /// Subtracts the color of the shape from the color underneath it, clipping channels at 0.
+    Subtract,
+
+    /// Takes the absolute difference between both colors.
+    Difference,
+
+    /// Every shape picks one of the other modes at random, so the modes evolve along with the shapes.
+    Random,
+}
+
+impl BlendMode {
+    /// Returns the mode of a new shape, which is never [BlendMode::Random].
+    pub(crate) fn resolve(self, random: &mut Random) -> BlendMode {
+        match self {
+            BlendMode::Random => MODES[random.get_random(0, MODES.len())],
+            mode => mode,
+        }
+    }
+
+    /// Returns the color of `source` combined with `destination` underneath it, before opacity is applied.
+    pub(crate) fn combine(self, destination: &Pixel, source: &Pixel) -> Pixel {
+        let combine_channel = |destination: u8, source: u8| {
+            let (destination, source) = (u16::from(destination), u16::from(source));
+
+            let channel = match self {
+                BlendMode::Normal | BlendMode::Random => source,
+                BlendMode::Multiply => (destination * source + 127) / 255,
+                BlendMode::Screen => 255 - ((255 - destination) * (255 - source) + 127) / 255,
+                BlendMode::Add => (destination + source).min(255),
+                BlendMode::Subtract => destination.saturating_sub(source),
+                BlendMode::Difference => destination.abs_diff(source),
+            };
+
+            channel as u8
+        };
+
+        Pixel::new(
+            combine_channel(destination.get_r(), source.get_r()),
+            combine_channel(destination.get_g(), source.get_g()),
+            combine_channel(destination.get_b(), source.get_b()),
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn combine_everyMode_channelsCombined() {
+        let destination = Pixel::new(200, 100, 0);
+        let source = Pixel::new(100, 255, 51);
+
+        assert_eq!(source, BlendMode::Normal.combine(&destination, &source));
+        assert_eq!(
+            Pixel::new(78, 100, 0),
+            BlendMode::Multiply.combine(&destination, &source)
+        );
+        assert_eq!(
+            Pixel::new(222, 255, 51),
+            BlendMode::Screen.combine(&destination, &source)
+        );
+        assert_eq!(
+            Pixel::new(255, 255, 51),
+            BlendMode::Add.combine(&destination, &source)
+        );
+        assert_eq!(
+            Pixel::new(100, 0, 0),
+            BlendMode::Subtract.combine(&destination, &source)
+        );
+        assert_eq!(
+            Pixel::new(100, 155, 51),
+            BlendMode::Difference.combine(&destination, &source)
+        );
+    }
+
+    #[test]
+    fn resolve_randomMode_concreteModesPicked() {
+        let mut random = Random::default();
+
+        let modes = (0..1000)
+            .map(|_| BlendMode::Random.resolve(&mut random))
+            .collect::<Vec<BlendMode>>();
+
+        assert!(!modes.contains(&BlendMode::Random));
+        assert!(MODES.iter().all(|mode| modes.contains(mode)));
+    }
+
+    #[test]
+    fn resolve_concreteMode_modeKept() {
+        let mut random = Random::default();
+
+        assert_eq!(BlendMode::Screen, BlendMode::Screen.resolve(&mut random));
+    }
+}
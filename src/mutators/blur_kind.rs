@@ -0,0 +1,13 @@
+/// This enum specifies different kinds of blur applied by [BlurMutator].
+///
+/// [BlurMutator]: crate::mutators::BlurMutator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlurKind {
+    /// Every pixel is replaced with the mean color of the square around it.
+    #[default]
+    Box,
+
+    /// Every pixel is replaced with the mean color of the square around it, weighted by a Gaussian function of the
+    /// distance, whose standard deviation is half of the radius.
+    Gaussian,
+}
@@ -0,0 +1,205 @@
+use anyhow::{Error, Result};
+
+use crate::{
+    models::{Image, Pixel},
+    ColorMode, RunConfiguration,
+};
+
+use super::{region::Region, BlurKind, MutationContext, Mutator, SizeSchedule};
+
+/// This mutator mutates the given specimen by blurring a random rectangular region.
+///
+/// Pixels of the region are blurred with their neighbors, including the ones outside of the region, so the blurred
+/// region blends with the rest of the specimen.
+#[derive(Debug)]
+pub struct BlurMutator {
+    kind: BlurKind,
+    radius: usize,
+    size_schedule: SizeSchedule,
+}
+
+impl BlurMutator {
+    /// Sets the kind of applied blur.
+    ///
+    /// This property is optional. The default value is: [BlurKind::Box].
+    pub fn set_kind(&mut self, kind: BlurKind) {
+        self.kind = kind;
+    }
+
+    /// Sets the radius of the blur in pixels, i.e. every pixel is blurred with the square of `2 * radius + 1` pixels
+    /// around it.
+    ///
+    /// This method returns an error if `radius` is zero.
+    ///
+    /// This property is optional. The default value is: 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::mutators::{BlurKind, BlurMutator};
+    ///
+    /// let mut mutator = BlurMutator::default();
+    ///
+    /// mutator.set_kind(BlurKind::Gaussian);
+    /// mutator.set_radius(2).unwrap();
+    /// ```
+    pub fn set_radius(&mut self, radius: usize) -> Result<()> {
+        if radius == 0 {
+            return Err(Error::msg("Radius of blur cannot be zero."));
+        }
+
+        self.radius = radius;
+
+        Ok(())
+    }
+
+    /// Sets how the maximal size of blurred regions changes during the simulation.
+    ///
+    /// This property is optional. The default value is: `SizeSchedule::Constant { size: 1.0 }`.
+    pub fn set_size_schedule(&mut self, size_schedule: SizeSchedule) {
+        self.size_schedule = size_schedule;
+    }
+
+    fn get_kernel(&self) -> Vec<f64> {
+        let radius = self.radius as i64;
+        let sigma = self.radius as f64 / 2f64;
+
+        (-radius..=radius)
+            .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+            .map(|(dx, dy)| match self.kind {
+                BlurKind::Box => 1f64,
+                BlurKind::Gaussian => {
+                    (-((dx * dx + dy * dy) as f64) / (2f64 * sigma * sigma)).exp()
+                }
+            })
+            .collect()
+    }
+}
+
+impl Default for BlurMutator {
+    fn default() -> Self {
+        Self {
+            kind: BlurKind::Box,
+            radius: 1,
+            size_schedule: SizeSchedule::default(),
+        }
+    }
+}
+
+impl Mutator for BlurMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let region = Region::random(context, image, &self.size_schedule);
+        let source = image.clone();
+        let kernel = self.get_kernel();
+
+        let image_width = image.width() as i64;
+        let image_height = image.height() as i64;
+        let radius = self.radius as i64;
+        let side = 2 * radius + 1;
+
+        for y in region.min_y as i64..region.max_y as i64 {
+            for x in region.min_x as i64..region.max_x as i64 {
+                let mut sums = [0f64; 3];
+                let mut total = 0f64;
+
+                // Neighbors outside of the image are skipped, and weights of the remaining ones are normalized.
+                for ny in (y - radius).max(0)..(y + radius + 1).min(image_height) {
+                    for nx in (x - radius).max(0)..(x + radius + 1).min(image_width) {
+                        let weight = kernel[((ny - y + radius) * side + nx - x + radius) as usize];
+                        let neighbor = &source[(ny * image_width + nx) as usize];
+
+                        sums[0] += weight * f64::from(neighbor.get_r());
+                        sums[1] += weight * f64::from(neighbor.get_g());
+                        sums[2] += weight * f64::from(neighbor.get_b());
+                        total += weight;
+                    }
+                }
+
+                let [r, g, b] = sums.map(|sum| (sum / total).round().clamp(0f64, 255f64) as u8);
+                let color = match context.get_color_mode() {
+                    ColorMode::Rgb => Pixel::new(r, g, b),
+                    ColorMode::Grayscale => Pixel::grayscale(r),
+                };
+
+                image[(y * image_width + x) as usize] = context.constrain_color(color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    fn get_checkerboard() -> Image {
+        let pixels = (0..100)
+            .map(|index| {
+                if (index / 10 + index % 10) % 2 == 0 {
+                    Pixel::grayscale(0)
+                } else {
+                    Pixel::grayscale(200)
+                }
+            })
+            .collect();
+
+        Image::new(10, 10, pixels)
+    }
+
+    /// Returns the sum of differences between horizontally adjacent pixels.
+    fn get_contrast(image: &Image) -> u64 {
+        let image_width = image.width();
+
+        (1..image.pixels().len())
+            .filter(|index| index % image_width != 0)
+            .map(|index| u64::from(image[index].get_r().abs_diff(image[index - 1].get_r())))
+            .sum()
+    }
+
+    #[test]
+    fn setRadius_zeroRadius_errorReturned() {
+        let mut mutator = BlurMutator::default();
+
+        assert!(mutator.set_radius(0).is_err());
+    }
+
+    #[test]
+    fn mutateRgb_boxBlur_contrastReduced() {
+        let mutator = BlurMutator::default();
+        let mut image = get_checkerboard();
+        let contrast = get_contrast(&image);
+
+        mutator.mutate_rgb(&mut image);
+
+        assert!(get_contrast(&image) < contrast);
+    }
+
+    #[test]
+    fn mutateGrayscale_gaussianBlurOfFlatImage_imageNotChanged() {
+        let mut mutator = BlurMutator::default();
+        mutator.set_kind(BlurKind::Gaussian);
+        mutator.set_radius(3).unwrap();
+        let mut image = Image::blank(10, 10, &Pixel::grayscale(77));
+
+        mutator.mutate_grayscale(&mut image);
+
+        assert!(image
+            .pixels()
+            .iter()
+            .all(|pixel| *pixel == Pixel::grayscale(77)));
+    }
+}
@@ -1,76 +1,114 @@
-use num_integer::Roots;
+use crate::{geometry::Circle, models::Image, ColorMode, RunConfiguration};
 
-use crate::{models::Image, util::Random};
-
-use super::Mutator;
-
-struct RandomCircle {
-    x: i64,
-    y: i64,
-    r: i64,
-}
+use super::{MutationContext, Mutator, ShapeStyle};
 
 /// This mutator mutates the given specimen by generating a random filled circle with random background color.
+///
+/// Circles may partially lie outside of the specimen, in which case they are clipped to its edges.
 #[derive(Debug, Default)]
-pub struct CircleMutator;
+pub struct CircleMutator {
+    style: ShapeStyle,
+}
 
 impl CircleMutator {
-    fn get_random_circle(&self, random: &mut Random, image: &Image) -> RandomCircle {
-        let image_width = image.width() as i64;
-        let image_height = image.height() as i64;
+    /// Creates a new mutator which paints circles with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self { style }
+    }
 
-        let x = random.get_random(1, image_width);
-        let y = random.get_random(1, image_height);
+    fn get_random_circle(&self, context: &mut MutationContext, image: &Image) -> Circle {
+        let max_size = self.style.get_max_size(context);
+        let max_r = (image.width().min(image.height()) as f64 / 2f64 * max_size).max(1f64);
 
-        let n = *[x, y, image_width - x, image_height - y]
-            .iter()
-            .min()
-            .unwrap();
+        let r = context.get_random().get_random(0.5, max_r + 0.5);
 
-        let r = random.get_random(1, n + 1);
+        // Uniformly placed circles may stick out of the image by up to their radius, so that pixels along the edges are
+        // as likely to be covered as the ones in the middle.
+        let center = self.style.get_random_center(context, image, r, r);
 
-        RandomCircle { x, y, r }
+        Circle {
+            x: center.x,
+            y: center.y,
+            r,
+        }
     }
 }
 
 impl Mutator for CircleMutator {
-    fn mutate_rgb(&self, image: &mut crate::models::Image) {
-        let mut random = Random::default();
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
 
-        let circle = self.get_random_circle(&mut random, image);
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
 
-        let r_channel = random.get_random(0u8, 255);
-        let g_channel = random.get_random(0u8, 255);
-        let b_channel = random.get_random(0u8, 255);
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let circle = self.get_random_circle(context, image);
 
-        for i in -circle.r..circle.r {
-            let height = (circle.r * circle.r - i * i).sqrt();
+        self.style.draw_closed(&circle, image, context);
+    }
 
-            for j in -height..height {
-                let idx = (j + circle.y) * image.width() as i64 + (i + circle.x);
-                let pixel = &mut image[idx as usize];
-                pixel.r(r_channel);
-                pixel.g(g_channel);
-                pixel.b(b_channel);
-            }
-        }
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
     }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{geometry::rasterize, models::Pixel, mutators::SizeSchedule};
+
+    fn get_mutator(max_size: f64) -> CircleMutator {
+        let mut style = ShapeStyle::default();
+        style.set_size_schedule(SizeSchedule::Constant { size: max_size });
 
-    fn mutate_grayscale(&self, image: &mut crate::models::Image) {
-        let mut random = Random::default();
+        CircleMutator::new(style)
+    }
 
-        let circle = self.get_random_circle(&mut random, image);
+    #[test]
+    fn getRandomCircle_anyCircle_radiusWithinLimitAndCircleTouchesImage() {
+        let mutator = get_mutator(0.5);
+        let image = Image::blank(20, 30, &Pixel::white());
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
 
-        let grayscale = random.get_random(0u8, 255);
+        for _ in 0..1000 {
+            let circle = mutator.get_random_circle(&mut context, &image);
 
-        for i in -circle.r..circle.r {
-            let height = (circle.r * circle.r - i * i).sqrt();
+            assert!((0.5..=5.5).contains(&circle.r));
+            assert!(circle.x > -circle.r && circle.x < 30f64 + circle.r);
+            assert!(circle.y > -circle.r && circle.y < 20f64 + circle.r);
+        }
+    }
 
-            for j in -height..height {
-                let idx = (j + circle.y) * image.width() as i64 + (i + circle.x);
-                let pixel = &mut image[idx as usize];
-                pixel.set_grayscale(grayscale);
-            }
+    #[test]
+    fn getRandomCircle_manyCircles_allPixelsCoveredEqually() {
+        let mutator = get_mutator(0.5);
+        let (width, height) = (8, 6);
+        let image = Image::blank(height, width, &Pixel::white());
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_seed(0);
+        let mut counts = vec![0; width * height];
+
+        for _ in 0..100_000 {
+            let circle = mutator.get_random_circle(&mut context, &image);
+            rasterize(&circle, width, height, false, |x, y, _| {
+                counts[y * width + x] += 1
+            });
         }
+
+        let min = *counts.iter().min().unwrap() as f64;
+        let max = *counts.iter().max().unwrap() as f64;
+        assert!(max / min < 1.15, "Coverage is not uniform: {:?}", counts);
     }
 }
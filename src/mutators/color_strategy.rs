@@ -0,0 +1,19 @@
+/// This enum specifies how colors of shapes generated by mutators are chosen.
+///
+/// Strategies other than [ColorStrategy::Random] need to know the target image, i.e. they only take effect when
+/// mutators are given a [MutationContext] with the target set. Otherwise colors are picked at random.
+///
+/// [MutationContext]: crate::mutators::MutationContext
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorStrategy {
+    /// Every color is picked uniformly at random.
+    #[default]
+    Random,
+
+    /// The color is set to the mean color of the target pixels covered by the shape.
+    Mean,
+
+    /// The color is chosen so that the painted shape is as close to the target as possible, in the least-squares
+    /// sense, taking into account the opacity of the shape and the current content of the specimen.
+    LeastSquares,
+}
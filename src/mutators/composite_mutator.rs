@@ -0,0 +1,180 @@
+use anyhow::{Error, Result};
+
+use crate::{models::Image, util::Random, ColorMode, RunConfiguration};
+
+use super::{MutationContext, Mutator};
+
+/// This mutator mutates the given specimen with one of its inner mutators, picked at random according to their
+/// weights.
+///
+/// A composite mutator without any inner mutators leaves specimens untouched.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::mutators::{CircleMutator, CompositeMutator, RectangleMutator, TriangleMutator};
+///
+/// let mut mutator = CompositeMutator::default();
+/// mutator.add_mutator(Box::new(RectangleMutator::default()), 0.5).unwrap();
+/// mutator.add_mutator(Box::new(TriangleMutator::default()), 0.3).unwrap();
+/// mutator.add_mutator(Box::new(CircleMutator::default()), 0.2).unwrap();
+/// ```
+#[derive(Default)]
+pub struct CompositeMutator {
+    mutators: Vec<(Box<dyn Mutator + Send + Sync>, f64)>,
+}
+
+impl CompositeMutator {
+    /// Adds an inner mutator, picked with probability proportional to `weight`.
+    ///
+    /// Returns an error if `weight` is not a positive number.
+    pub fn add_mutator(
+        &mut self,
+        mutator: Box<dyn Mutator + Send + Sync>,
+        weight: f64,
+    ) -> Result<()> {
+        if !weight.is_finite() || weight <= 0f64 {
+            return Err(Error::msg("Weight of a mutator must be a positive number."));
+        }
+
+        self.mutators.push((mutator, weight));
+
+        Ok(())
+    }
+
+    /// Returns the number of inner mutators.
+    pub fn len(&self) -> usize {
+        self.mutators.len()
+    }
+
+    /// Returns `true` if there are no inner mutators.
+    pub fn is_empty(&self) -> bool {
+        self.mutators.is_empty()
+    }
+
+    fn pick_mutator(&self, random: &mut Random) -> Option<&(dyn Mutator + Send + Sync)> {
+        if self.mutators.is_empty() {
+            return None;
+        }
+
+        let total = self.mutators.iter().map(|(_, weight)| weight).sum::<f64>();
+
+        let mut threshold = random.get_random(0f64, total);
+        let index = self
+            .mutators
+            .iter()
+            .position(|(_, weight)| {
+                threshold -= weight;
+                threshold < 0f64
+            })
+            .unwrap_or(self.mutators.len() - 1);
+
+        Some(self.mutators[index].0.as_ref())
+    }
+}
+
+impl Mutator for CompositeMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        if let Some(mutator) = self.pick_mutator(context.get_random()) {
+            mutator.mutate(image, context);
+        }
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.mutators
+            .iter()
+            .any(|(mutator, _)| mutator.uses_error_map())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{
+        models::Pixel,
+        mutators::{Placement, RectangleMutator, ShapeStyle},
+    };
+
+    struct FillMutator(u8);
+
+    impl Mutator for FillMutator {
+        fn mutate_rgb(&self, image: &mut Image) {
+            *image = Image::blank(image.height(), image.width(), &Pixel::grayscale(self.0));
+        }
+
+        fn mutate_grayscale(&self, image: &mut Image) {
+            self.mutate_rgb(image);
+        }
+    }
+
+    #[test]
+    fn addMutator_nonPositiveWeight_errorReturned() {
+        let mut mutator = CompositeMutator::default();
+
+        assert!(mutator.add_mutator(Box::new(FillMutator(0)), 0f64).is_err());
+        assert!(mutator
+            .add_mutator(Box::new(FillMutator(0)), -1f64)
+            .is_err());
+        assert!(mutator
+            .add_mutator(Box::new(FillMutator(0)), f64::NAN)
+            .is_err());
+        assert!(mutator.is_empty());
+    }
+
+    #[test]
+    fn mutateRgb_noInnerMutators_imageNotChanged() {
+        let mutator = CompositeMutator::default();
+        let mut image = Image::blank(2, 2, &Pixel::white());
+
+        mutator.mutate_rgb(&mut image);
+
+        assert!(image.pixels().iter().all(|pixel| *pixel == Pixel::white()));
+    }
+
+    #[test]
+    fn mutateRgb_manyMutations_mutatorsPickedAccordingToWeights() {
+        let mut mutator = CompositeMutator::default();
+        mutator.add_mutator(Box::new(FillMutator(0)), 3f64).unwrap();
+        mutator.add_mutator(Box::new(FillMutator(1)), 1f64).unwrap();
+        let mut image = Image::blank(1, 1, &Pixel::white());
+
+        let first = (0..4000)
+            .filter(|_| {
+                mutator.mutate_rgb(&mut image);
+                image[0] == Pixel::grayscale(0)
+            })
+            .count();
+
+        assert!((2700..3300).contains(&first));
+    }
+
+    #[test]
+    fn usesErrorMap_innerMutatorWithErrorGuidedPlacement_errorMapUsed() {
+        let mut style = ShapeStyle::default();
+        style.set_placement(Placement::ErrorGuided);
+        let mut mutator = CompositeMutator::default();
+        mutator.add_mutator(Box::new(FillMutator(0)), 1f64).unwrap();
+        assert!(!mutator.uses_error_map());
+
+        mutator
+            .add_mutator(Box::new(RectangleMutator::new(style)), 1f64)
+            .unwrap();
+
+        assert!(mutator.uses_error_map());
+    }
+}
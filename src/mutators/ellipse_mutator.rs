@@ -0,0 +1,139 @@
+use std::f64::consts::PI;
+
+use crate::{
+    geometry::{Ellipse, Point},
+    models::Image,
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::{MutationContext, Mutator, ShapeStyle};
+
+/// This mutator mutates the given specimen by generating a random filled and rotated ellipse with random background
+/// color.
+///
+/// Ellipses may partially lie outside of the specimen, in which case they are clipped to its edges.
+#[derive(Debug, Default)]
+pub struct EllipseMutator {
+    style: ShapeStyle,
+}
+
+impl EllipseMutator {
+    /// Creates a new mutator which paints ellipses with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self { style }
+    }
+
+    fn get_random_ellipse(
+        &self,
+        random: &mut Random,
+        image: &Image,
+        position: Point,
+        max_size: f64,
+    ) -> Ellipse {
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+
+        let max_semi_axis = (image_width.min(image_height) / 2f64 * max_size).max(2f64);
+
+        // Both semi-axes are picked independently, the longer one becomes the major axis.
+        let first_semi_axis = random.get_random(1f64, max_semi_axis);
+        let second_semi_axis = random.get_random(1f64, max_semi_axis);
+
+        Ellipse {
+            x: position.x,
+            y: position.y,
+            semi_major: first_semi_axis.max(second_semi_axis),
+            semi_minor: first_semi_axis.min(second_semi_axis),
+            angle: random.get_random(0f64, PI),
+        }
+    }
+}
+
+impl Mutator for EllipseMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let max_size = self.style.get_max_size(context);
+        let position = self.style.get_random_position(context, image);
+        let ellipse = self.get_random_ellipse(context.get_random(), image, position, max_size);
+
+        self.style.draw(&ellipse, image, context);
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{geometry::Shape, models::Pixel, mutators::SizeSchedule};
+
+    fn get_mutator(max_size: f64) -> EllipseMutator {
+        let mut style = ShapeStyle::default();
+        style.set_size_schedule(SizeSchedule::Constant { size: max_size });
+
+        EllipseMutator::new(style)
+    }
+
+    #[test]
+    fn getRandomEllipse_anyEllipse_semiAxesWithinLimitAndOrdered() {
+        let mutator = get_mutator(0.5);
+        let image = Image::blank(20, 30, &Pixel::white());
+        let mut random = Random::default();
+
+        for _ in 0..1000 {
+            let ellipse =
+                mutator.get_random_ellipse(&mut random, &image, Point::new(15f64, 10f64), 0.5);
+
+            assert!((1f64..5f64).contains(&ellipse.semi_minor));
+            assert!((1f64..5f64).contains(&ellipse.semi_major));
+            assert!(ellipse.semi_minor <= ellipse.semi_major);
+        }
+    }
+
+    #[test]
+    fn draw_anyEllipse_drawnPixelsWithinBoundsOfEllipse() {
+        let mutator = get_mutator(0.5);
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_seed(3);
+
+        for _ in 0..100 {
+            let mut image = Image::blank(20, 30, &Pixel::white());
+            let position = mutator.style.get_random_position(&mut context, &image);
+            let ellipse = mutator.get_random_ellipse(context.get_random(), &image, position, 0.5);
+            let bounds = ellipse.bounds();
+
+            mutator.style.draw(&ellipse, &mut image, &mut context);
+
+            for y in 0..20 {
+                for x in 0..30 {
+                    if image[y * 30 + x] != Pixel::white() {
+                        let (center_x, center_y) = (x as f64 + 0.5, y as f64 + 0.5);
+
+                        assert!((bounds.min_x..bounds.max_x).contains(&center_x));
+                        assert!((bounds.min_y..bounds.max_y).contains(&center_y));
+                        assert!(ellipse.contains(center_x, center_y));
+                    }
+                }
+            }
+        }
+    }
+}
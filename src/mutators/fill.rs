@@ -0,0 +1,19 @@
+/// This enum specifies how the interior of shapes generated by mutators is filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fill {
+    /// Fills shapes with a single color.
+    #[default]
+    Flat,
+
+    /// Fills shapes with colors changing linearly between two random points.
+    LinearGradient {
+        /// Number of colors the gradient passes through. Gradients always have at least two color stops.
+        stops: usize,
+    },
+
+    /// Fills shapes with colors changing with the distance from a random center.
+    RadialGradient {
+        /// Number of colors the gradient passes through. Gradients always have at least two color stops.
+        stops: usize,
+    },
+}
@@ -0,0 +1,148 @@
+use std::f64::consts::PI;
+
+use crate::{
+    geometry::{Gaussian, Point},
+    models::Image,
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::{MutationContext, Mutator, ShapeStyle};
+
+/// This mutator mutates the given specimen by depositing a random soft-edged Gaussian splat with random background
+/// color.
+///
+/// The opacity of a splat fades out smoothly with the distance from its center, so splats blend into smooth gradients
+/// which hard-edged shapes can't match. Splats are always soft, regardless of anti-aliasing of the style.
+#[derive(Debug, Default)]
+pub struct GaussianMutator {
+    style: ShapeStyle,
+    anisotropic: bool,
+}
+
+impl GaussianMutator {
+    /// Creates a new mutator which paints splats with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether generated splats can be stretched in a random direction, instead of being round.
+    ///
+    /// This property is optional. The default value is: `false`.
+    pub fn set_anisotropic(&mut self, anisotropic: bool) {
+        self.anisotropic = anisotropic;
+    }
+
+    fn get_random_splat(
+        &self,
+        random: &mut Random,
+        image: &Image,
+        position: Point,
+        max_size: f64,
+    ) -> Gaussian {
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+
+        // Splats fade out three standard deviations away from the center, so the largest one roughly fills the image.
+        let max_sigma = (image_width.min(image_height) / 6f64 * max_size).max(1f64);
+        let sigma = random.get_random(0.5, max_sigma + 0.5);
+
+        let (sigma_minor, angle) = if self.anisotropic {
+            (
+                random.get_random(0.5, sigma + 0.5),
+                random.get_random(0f64, PI),
+            )
+        } else {
+            (sigma, 0f64)
+        };
+
+        Gaussian {
+            x: position.x,
+            y: position.y,
+            sigma_major: sigma,
+            sigma_minor,
+            angle,
+        }
+    }
+}
+
+impl Mutator for GaussianMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let max_size = self.style.get_max_size(context);
+        let position = self.style.get_random_position(context, image);
+        let splat = self.get_random_splat(context.get_random(), image, position, max_size);
+
+        self.style.draw(&splat, image, context);
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{models::Pixel, mutators::ColorStrategy};
+
+    #[test]
+    fn getRandomSplat_notAnisotropic_splatsRound() {
+        let mutator = GaussianMutator::default();
+        let image = Image::blank(30, 30, &Pixel::white());
+        let mut random = Random::default();
+
+        for _ in 0..100 {
+            let splat =
+                mutator.get_random_splat(&mut random, &image, Point::new(15f64, 15f64), 1f64);
+
+            assert_eq!(splat.sigma_major, splat.sigma_minor);
+            assert!(splat.sigma_major <= 5.5);
+        }
+    }
+
+    #[test]
+    fn mutate_anySplat_opacityFadesWithDistanceFromCenter() {
+        let mut style = ShapeStyle::default();
+        style.set_color_strategy(ColorStrategy::Mean);
+        let target = Image::blank(20, 20, &Pixel::grayscale(0));
+        let mut image = Image::blank(20, 20, &Pixel::white());
+        let splat = Gaussian {
+            x: 10f64,
+            y: 10f64,
+            sigma_major: 2f64,
+            sigma_minor: 2f64,
+            angle: 0f64,
+        };
+
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_target(&target);
+        style.draw(&splat, &mut image, &mut context);
+
+        let center = image[10 * 20 + 10].get_r();
+        let near = image[10 * 20 + 12].get_r();
+        let far = image[10 * 20 + 19].get_r();
+        assert!(center < near);
+        assert!(near < far);
+        assert_eq!(255, far);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::models::Image;
+
+/// Gradients of brightness smaller than this value are treated as flat areas without a direction.
+const MIN_MAGNITUDE: f64 = 8f64;
+
+/// Directions of edges of an image, calculated with the Sobel operator.
+#[derive(Debug, Clone)]
+pub(crate) struct GradientField {
+    width: usize,
+    height: usize,
+    gradients: Vec<(f64, f64)>,
+}
+
+impl GradientField {
+    #[must_use]
+    pub fn new(image: &Image) -> Self {
+        let width = image.width();
+        let height = image.height();
+
+        let luminance = |x: isize, y: isize| {
+            let x = x.clamp(0, width as isize - 1) as usize;
+            let y = y.clamp(0, height as isize - 1) as usize;
+            image[y * width + x].luminance()
+        };
+
+        let mut gradients = Vec::with_capacity(width * height);
+        for y in 0..height as isize {
+            for x in 0..width as isize {
+                let gx =
+                    luminance(x + 1, y - 1) + 2f64 * luminance(x + 1, y) + luminance(x + 1, y + 1)
+                        - luminance(x - 1, y - 1)
+                        - 2f64 * luminance(x - 1, y)
+                        - luminance(x - 1, y + 1);
+                let gy =
+                    luminance(x - 1, y + 1) + 2f64 * luminance(x, y + 1) + luminance(x + 1, y + 1)
+                        - luminance(x - 1, y - 1)
+                        - 2f64 * luminance(x, y - 1)
+                        - luminance(x + 1, y - 1);
+
+                gradients.push((gx, gy));
+            }
+        }
+
+        Self {
+            width,
+            height,
+            gradients,
+        }
+    }
+
+    /// Returns the angle in radians of the edge passing through the point, i.e. the direction perpendicular to the
+    /// gradient. Returns `None` if the point lies outside of the image or in a flat area.
+    pub fn get_edge_angle(&self, x: f64, y: f64) -> Option<f64> {
+        if x < 0f64 || y < 0f64 || x >= self.width as f64 || y >= self.height as f64 {
+            return None;
+        }
+
+        let (gx, gy) = self.gradients[y as usize * self.width + x as usize];
+
+        if gx.hypot(gy) < MIN_MAGNITUDE {
+            None
+        } else {
+            Some(gy.atan2(gx) + std::f64::consts::FRAC_PI_2)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::models::Pixel;
+
+    #[test]
+    fn getEdgeAngle_verticalEdge_verticalAngleReturned() {
+        let pixels = (0..100)
+            .map(|idx| {
+                if idx % 10 < 5 {
+                    Pixel::grayscale(0)
+                } else {
+                    Pixel::white()
+                }
+            })
+            .collect::<Vec<Pixel>>();
+        let field = GradientField::new(&Image::new(10, 10, pixels));
+
+        let angle = field.get_edge_angle(5f64, 5f64).unwrap();
+
+        assert!((angle.sin().abs() - FRAC_PI_2.sin()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn getEdgeAngle_flatArea_noneReturned() {
+        let field = GradientField::new(&Image::blank(10, 10, &Pixel::white()));
+
+        assert_eq!(None, field.get_edge_angle(5f64, 5f64));
+    }
+
+    #[test]
+    fn getEdgeAngle_pointOutsideImage_noneReturned() {
+        let field = GradientField::new(&Image::blank(10, 10, &Pixel::white()));
+
+        assert_eq!(None, field.get_edge_angle(-1f64, 5f64));
+        assert_eq!(None, field.get_edge_angle(5f64, 10f64));
+    }
+}
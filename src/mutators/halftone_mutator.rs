@@ -0,0 +1,290 @@
+use std::f64::consts::SQRT_2;
+
+use anyhow::{Error, Result};
+
+use crate::{
+    geometry::{Bounds, Circle, Shape},
+    models::{Image, Pixel},
+    ColorMode, RunConfiguration,
+};
+
+use super::{MutationContext, Mutator, ShapeStyle};
+
+/// A dot of a halftone, clipped to its cell.
+struct Dot {
+    circle: Circle,
+    cell: Bounds,
+}
+
+impl Shape for Dot {
+    fn bounds(&self) -> Bounds {
+        let bounds = self.circle.bounds();
+
+        Bounds {
+            min_x: bounds.min_x.max(self.cell.min_x),
+            min_y: bounds.min_y.max(self.cell.min_y),
+            max_x: bounds.max_x.min(self.cell.max_x),
+            max_y: bounds.max_y.min(self.cell.max_y),
+        }
+    }
+
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        let cell = (self.cell.min_x - x)
+            .max(x - self.cell.max_x)
+            .max(self.cell.min_y - y)
+            .max(y - self.cell.max_y);
+
+        self.circle.signed_distance(x, y).max(cell)
+    }
+}
+
+/// Returns a fixed offset of the dot in the given cell, in range `[-1, 1)` along both axes.
+///
+/// The offset is derived from the position of the cell, so every dot stays in the same place for the whole simulation.
+fn get_offset(column: usize, row: usize) -> (f64, f64) {
+    // SplitMix64 finalizer, which spreads neighbouring cells far apart.
+    let mut hash = ((column as u64) << 32 ^ row as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^= hash >> 31;
+
+    let to_offset = |bits: u64| (bits & 0xffff_ffff) as f64 / f64::from(1u32 << 31) - 1f64;
+
+    (to_offset(hash), to_offset(hash >> 32))
+}
+
+/// This mutator mutates the given specimen by repainting a single cell of a halftone grid with a dot of random radius
+/// and color.
+///
+/// Every cell holds exactly one dot on a background, so the specimen approximates the target like a printed halftone,
+/// or like a pointillist painting when dots are jittered. Grayscale mode with the default white background produces
+/// classic newspaper halftone. The cell is picked according to the placement of the style, and the dot is painted with
+/// its alpha, fill and color strategy, while the size schedule doesn't apply.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::mutators::{HalftoneMutator, ShapeStyle};
+///
+/// let mut mutator = HalftoneMutator::new(ShapeStyle::default());
+/// mutator.set_cell_size(6).unwrap();
+/// mutator.set_jitter(0.5).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct HalftoneMutator {
+    style: ShapeStyle,
+    cell_size: usize,
+    jitter: f64,
+    background: Pixel,
+}
+
+impl HalftoneMutator {
+    /// Creates a new mutator which paints dots with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the length in pixels of the side of a single cell of the grid.
+    ///
+    /// This property is optional. The default value is: `8`.
+    ///
+    /// Returns an error if `cell_size` is 0.
+    pub fn set_cell_size(&mut self, cell_size: usize) -> Result<()> {
+        if cell_size == 0 {
+            return Err(Error::msg("Size of a cell must be a positive integer."));
+        }
+
+        self.cell_size = cell_size;
+
+        Ok(())
+    }
+
+    /// Sets how far dots can be moved from the centers of their cells, as a fraction of half of the cell size. Dots
+    /// of a regular grid are not moved at all.
+    ///
+    /// This property is optional. The default value is: `0`.
+    ///
+    /// Returns an error if `jitter` is not in range `[0, 1]`.
+    pub fn set_jitter(&mut self, jitter: f64) -> Result<()> {
+        if !(0f64..=1f64).contains(&jitter) {
+            return Err(Error::msg("Jitter must be a number between 0 and 1."));
+        }
+
+        self.jitter = jitter;
+
+        Ok(())
+    }
+
+    /// Sets the color of cells around dots.
+    ///
+    /// This property is optional. The default value is: white.
+    pub fn set_background(&mut self, background: Pixel) {
+        self.background = background;
+    }
+
+    fn get_random_dot(&self, context: &mut MutationContext, image: &Image) -> Dot {
+        let position = self.style.get_random_position(context, image);
+        let column = (position.x.max(0f64) as usize).min(image.width() - 1) / self.cell_size;
+        let row = (position.y.max(0f64) as usize).min(image.height() - 1) / self.cell_size;
+
+        let cell_size = self.cell_size as f64;
+        let cell = Bounds {
+            min_x: column as f64 * cell_size,
+            min_y: row as f64 * cell_size,
+            max_x: ((column + 1) as f64 * cell_size).min(image.width() as f64),
+            max_y: ((row + 1) as f64 * cell_size).min(image.height() as f64),
+        };
+
+        let (offset_x, offset_y) = get_offset(column, row);
+        let reach = self.jitter * cell_size / 2f64;
+
+        // The largest dot covers the whole cell, even when it's moved towards one of its corners.
+        let max_r = cell_size / SQRT_2 * (1f64 + self.jitter);
+
+        Dot {
+            circle: Circle {
+                x: (column as f64 + 0.5) * cell_size + offset_x * reach,
+                y: (row as f64 + 0.5) * cell_size + offset_y * reach,
+                r: context.get_random().get_random(0f64, max_r),
+            },
+            cell,
+        }
+    }
+}
+
+impl Default for HalftoneMutator {
+    fn default() -> Self {
+        Self {
+            style: ShapeStyle::default(),
+            cell_size: 8,
+            jitter: 0f64,
+            background: Pixel::white(),
+        }
+    }
+}
+
+impl Mutator for HalftoneMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let dot = self.get_random_dot(context, image);
+
+        let background = match context.get_color_mode() {
+            ColorMode::Rgb => self.background.clone(),
+            ColorMode::Grayscale => Pixel::grayscale(self.background.luminance().round() as u8),
+        };
+        let background = context.constrain_color(background);
+
+        let image_width = image.width();
+        for y in dot.cell.min_y as usize..dot.cell.max_y as usize {
+            for x in dot.cell.min_x as usize..dot.cell.max_x as usize {
+                image[y * image_width + x] = background.clone();
+            }
+        }
+
+        self.style.draw(&dot, image, context);
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    fn get_cells(image: &Image, cell_size: usize) -> Vec<Vec<Pixel>> {
+        let mut cells = Vec::new();
+        for row in (0..image.height()).step_by(cell_size) {
+            for column in (0..image.width()).step_by(cell_size) {
+                let mut cell = Vec::new();
+                for y in row..(row + cell_size).min(image.height()) {
+                    for x in column..(column + cell_size).min(image.width()) {
+                        cell.push(image[y * image.width() + x].clone());
+                    }
+                }
+                cells.push(cell);
+            }
+        }
+
+        cells
+    }
+
+    #[test]
+    fn setCellSize_zeroGiven_errorReturned() {
+        let mut mutator = HalftoneMutator::default();
+
+        assert!(mutator.set_cell_size(0).is_err());
+    }
+
+    #[test]
+    fn setJitter_outOfRangeGiven_errorReturned() {
+        let mut mutator = HalftoneMutator::default();
+
+        assert!(mutator.set_jitter(-0.1).is_err());
+        assert!(mutator.set_jitter(1.1).is_err());
+        assert!(mutator.set_jitter(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn mutateGrayscale_singleMutation_onlyOneCellChanged() {
+        let mutator = HalftoneMutator::default();
+        let blank = Image::blank(20, 20, &Pixel::grayscale(100));
+        let mut image = blank.clone();
+
+        mutator.mutate_grayscale(&mut image);
+
+        let changed = get_cells(&image, 8)
+            .iter()
+            .zip(get_cells(&blank, 8))
+            .filter(|(cell, blank)| **cell != *blank)
+            .count();
+        assert_eq!(1, changed);
+    }
+
+    #[test]
+    fn mutateGrayscale_manyMutations_everyCellHoldsDotOnBackground() {
+        let mut mutator = HalftoneMutator::default();
+        mutator.set_jitter(1f64).unwrap();
+        let mut image = Image::blank(20, 20, &Pixel::grayscale(100));
+
+        for _ in 0..200 {
+            mutator.mutate_grayscale(&mut image);
+        }
+
+        for cell in get_cells(&image, 8) {
+            let dot = cell.iter().find(|pixel| **pixel != Pixel::white());
+            assert!(cell
+                .iter()
+                .all(|pixel| *pixel == Pixel::white() || Some(pixel) == dot));
+        }
+    }
+
+    #[test]
+    fn getOffset_sameCell_sameOffset() {
+        assert_eq!(get_offset(3, 5), get_offset(3, 5));
+        assert_ne!(get_offset(3, 5), get_offset(5, 3));
+
+        let (x, y) = get_offset(7, 2);
+        assert!((-1f64..1f64).contains(&x));
+        assert!((-1f64..1f64).contains(&y));
+    }
+}
@@ -1,6 +1,15 @@
 mod mutator;
 pub use mutator::Mutator;
 
+mod mutation_context;
+pub use mutation_context::MutationContext;
+
+mod alpha;
+pub use alpha::Alpha;
+
+mod shape_style;
+pub use shape_style::ShapeStyle;
+
 mod circle_mutator;
 pub use circle_mutator::CircleMutator;
 
@@ -9,3 +18,72 @@ pub use triangle_mutator::TriangleMutator;
 
 mod rectangle_mutator;
 pub use rectangle_mutator::RectangleMutator;
+
+mod ellipse_mutator;
+pub use ellipse_mutator::EllipseMutator;
+
+mod gaussian_mutator;
+pub use gaussian_mutator::GaussianMutator;
+
+mod polygon_kind;
+pub use polygon_kind::PolygonKind;
+
+mod polygon_mutator;
+pub use polygon_mutator::PolygonMutator;
+
+mod gradient_field;
+
+mod stroke_mutator;
+pub use stroke_mutator::StrokeMutator;
+
+mod bezier_degree;
+pub use bezier_degree::BezierDegree;
+
+mod bezier_shape;
+pub use bezier_shape::BezierShape;
+
+mod bezier_mutator;
+pub use bezier_mutator::BezierMutator;
+
+mod halftone_mutator;
+pub use halftone_mutator::HalftoneMutator;
+
+mod stamp_mutator;
+pub use stamp_mutator::StampMutator;
+
+mod region;
+
+mod noise_mutator;
+pub use noise_mutator::NoiseMutator;
+
+mod blur_kind;
+pub use blur_kind::BlurKind;
+
+mod blur_mutator;
+pub use blur_mutator::BlurMutator;
+
+mod composite_mutator;
+pub use composite_mutator::CompositeMutator;
+
+mod symmetry;
+pub use symmetry::Symmetry;
+
+mod symmetric_mutator;
+pub use symmetric_mutator::SymmetricMutator;
+
+mod fill;
+pub use fill::Fill;
+
+mod paint;
+
+mod blend_mode;
+pub use blend_mode::BlendMode;
+
+mod color_strategy;
+pub use color_strategy::ColorStrategy;
+
+mod placement;
+pub use placement::Placement;
+
+mod size_schedule;
+pub use size_schedule::SizeSchedule;
@@ -0,0 +1,174 @@
+use rand::rngs::StdRng;
+
+use crate::{
+    models::{ErrorMap, Image, Palette, Pixel},
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::BlendMode;
+
+/// This struct describes the state of the simulation in which a specimen is mutated.
+///
+/// Every mutation gets its own context. The environment seeds its random number generator from a generator of the
+/// whole simulation, so mutators which draw random numbers only from [MutationContext::get_rng] are deterministic when
+/// the simulation is seeded.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::{mutators::MutationContext, ColorMode, Image, Pixel, RunConfiguration};
+///
+/// let configuration = RunConfiguration::new(ColorMode::Rgb);
+/// let target = Image::blank(10, 10, &Pixel::white());
+///
+/// let mut context = MutationContext::new(&configuration);
+/// context.set_seed(42);
+/// context.set_generation(7);
+/// context.set_target(&target);
+/// ```
+#[derive(Debug)]
+pub struct MutationContext<'a> {
+    configuration: &'a RunConfiguration,
+    random: Random,
+    generation: u32,
+    stalled_generations: u32,
+    target: Option<&'a Image>,
+    error_map: Option<&'a ErrorMap>,
+}
+
+impl<'a> MutationContext<'a> {
+    /// Creates a new context for mutations performed in a simulation with the given configuration.
+    ///
+    /// The random number generator of the context is seeded from entropy.
+    #[must_use]
+    pub fn new(configuration: &'a RunConfiguration) -> Self {
+        Self {
+            configuration,
+            random: Random::default(),
+            generation: 0,
+            stalled_generations: 0,
+            target: None,
+            error_map: None,
+        }
+    }
+
+    /// Reseeds the random number generator of the context.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = Random::from_seed(seed);
+    }
+
+    /// Sets the number of the generation in which the mutation is performed, starting from 0.
+    pub fn set_generation(&mut self, generation: u32) {
+        self.generation = generation;
+    }
+
+    /// Sets the number of previous generations which didn't improve the best specimen.
+    pub fn set_stalled_generations(&mut self, stalled_generations: u32) {
+        self.stalled_generations = stalled_generations;
+    }
+
+    /// Sets the image which the simulation tries to recreate.
+    pub fn set_target(&mut self, target: &'a Image) {
+        self.target = Some(target);
+    }
+
+    /// Sets the error map of the best specimen from the previous generation.
+    pub fn set_error_map(&mut self, error_map: &'a ErrorMap) {
+        self.error_map = Some(error_map);
+    }
+
+    /// Returns settings of the simulation.
+    pub fn get_configuration(&self) -> &'a RunConfiguration {
+        self.configuration
+    }
+
+    /// Returns the color mode in which the specimen is mutated.
+    pub fn get_color_mode(&self) -> ColorMode {
+        self.configuration.get_color_mode()
+    }
+
+    /// Returns the palette which colors of shapes are restricted to, if there is one.
+    pub fn get_palette(&self) -> Option<&'a Palette> {
+        self.configuration.get_palette()
+    }
+
+    /// Returns how colors of shapes are combined with colors of the specimen underneath them, unless the style of a
+    /// mutator overrides it.
+    pub fn get_blend_mode(&self) -> BlendMode {
+        self.configuration.get_blend_mode()
+    }
+
+    /// Returns the random number generator which mutators should use.
+    pub fn get_rng(&mut self) -> &mut StdRng {
+        self.random.get_rng()
+    }
+
+    /// Returns the number of the generation in which the mutation is performed, starting from 0.
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Returns the number of previous generations which didn't improve the best specimen.
+    pub fn get_stalled_generations(&self) -> u32 {
+        self.stalled_generations
+    }
+
+    /// Returns the image which the simulation tries to recreate, if it's known.
+    pub fn get_target(&self) -> Option<&'a Image> {
+        self.target
+    }
+
+    /// Returns the error map of the best specimen from the previous generation, if it's known.
+    pub fn get_error_map(&self) -> Option<&'a ErrorMap> {
+        self.error_map
+    }
+
+    pub(crate) fn get_random(&mut self) -> &mut Random {
+        &mut self.random
+    }
+
+    /// Returns a random color, which can be painted in this context.
+    pub(crate) fn get_random_color(&mut self) -> Pixel {
+        match (self.get_palette(), self.get_color_mode()) {
+            (Some(palette), _) => {
+                let colors = palette.get_colors();
+                colors[self.random.get_random(0, colors.len())].clone()
+            }
+            (None, ColorMode::Rgb) => Pixel::new(
+                self.random.get_random(0u8, 255),
+                self.random.get_random(0u8, 255),
+                self.random.get_random(0u8, 255),
+            ),
+            (None, ColorMode::Grayscale) => Pixel::grayscale(self.random.get_random(0u8, 255)),
+        }
+    }
+
+    /// Returns the color closest to `color`, which can be painted in this context.
+    pub(crate) fn constrain_color(&self, color: Pixel) -> Pixel {
+        match self.get_palette() {
+            Some(palette) => palette.get_closest(&color).clone(),
+            None => color,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use rand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn setSeed_sameSeeds_sameNumbersGenerated() {
+        let configuration = RunConfiguration::default();
+        let mut first = MutationContext::new(&configuration);
+        let mut second = MutationContext::new(&configuration);
+
+        first.set_seed(7);
+        second.set_seed(7);
+
+        assert_eq!(first.get_rng().gen::<u64>(), second.get_rng().gen::<u64>());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::models::Image;
+use crate::{models::Image, ColorMode};
+
+use super::MutationContext;
 
 /// This trait defines functionality used to mutate a single specimen.
 ///
@@ -17,4 +19,25 @@ pub trait Mutator {
     ///
     /// This method performs the mutation with respect to only one color channel.
     fn mutate_grayscale(&self, image: &mut Image);
+
+    /// This method mutates the given image, with respect to the state of the simulation described by `context`.
+    ///
+    /// This is the method called by the environment. The default implementation ignores everything but the color
+    /// mode and calls either [Mutator::mutate_rgb] or [Mutator::mutate_grayscale], so mutators which don't need the
+    /// context don't have to implement it. Mutators which implement it should draw random numbers from
+    /// [MutationContext::get_rng], so that seeded simulations are reproducible.
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        match context.get_color_mode() {
+            ColorMode::Rgb => self.mutate_rgb(image),
+            ColorMode::Grayscale => self.mutate_grayscale(image),
+        }
+    }
+
+    /// Returns whether this mutator needs the error map of the best specimen, see [MutationContext::get_error_map].
+    ///
+    /// Environments compute the error map only if at least one of their mutators needs it. The default implementation
+    /// returns `false`.
+    fn uses_error_map(&self) -> bool {
+        false
+    }
 }
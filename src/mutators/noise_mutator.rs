@@ -0,0 +1,157 @@
+use std::f64::consts::TAU;
+
+use anyhow::{Error, Result};
+
+use crate::{
+    models::{Image, Pixel},
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::{region::Region, MutationContext, Mutator, SizeSchedule};
+
+/// Returns a random number from the standard normal distribution, using the Box-Muller transform.
+fn get_gaussian(random: &mut Random) -> f64 {
+    let u = 1f64 - random.get_random(0f64, 1f64);
+    let v = random.get_random(0f64, 1f64);
+
+    (-2f64 * u.ln()).sqrt() * (TAU * v).cos()
+}
+
+/// This mutator mutates the given specimen by adding Gaussian noise to every pixel of a random rectangular region.
+///
+/// In RGB mode every color channel gets its own noise, while in grayscale mode all channels change together.
+#[derive(Debug)]
+pub struct NoiseMutator {
+    strength: f64,
+    size_schedule: SizeSchedule,
+}
+
+impl NoiseMutator {
+    /// Sets the standard deviation of the noise, in units of color channels (0-255).
+    ///
+    /// This method returns an error if `strength` is not a positive number.
+    ///
+    /// This property is optional. The default value is: 16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::mutators::NoiseMutator;
+    ///
+    /// let mut mutator = NoiseMutator::default();
+    ///
+    /// mutator.set_strength(8.0).unwrap();
+    /// ```
+    pub fn set_strength(&mut self, strength: f64) -> Result<()> {
+        if !strength.is_finite() || strength <= 0f64 {
+            return Err(Error::msg("Strength of noise must be a positive number."));
+        }
+
+        self.strength = strength;
+
+        Ok(())
+    }
+
+    /// Sets how the maximal size of noisy regions changes during the simulation.
+    ///
+    /// This property is optional. The default value is: `SizeSchedule::Constant { size: 1.0 }`.
+    pub fn set_size_schedule(&mut self, size_schedule: SizeSchedule) {
+        self.size_schedule = size_schedule;
+    }
+
+    fn add_noise(&self, random: &mut Random, channel: u8) -> u8 {
+        (f64::from(channel) + self.strength * get_gaussian(random))
+            .round()
+            .clamp(0f64, 255f64) as u8
+    }
+}
+
+impl Default for NoiseMutator {
+    fn default() -> Self {
+        Self {
+            strength: 16f64,
+            size_schedule: SizeSchedule::default(),
+        }
+    }
+}
+
+impl Mutator for NoiseMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let region = Region::random(context, image, &self.size_schedule);
+        let color_mode = context.get_color_mode();
+        let image_width = image.width();
+
+        for y in region.min_y..region.max_y {
+            for x in region.min_x..region.max_x {
+                let pixel = &image[y * image_width + x];
+                let random = context.get_random();
+
+                let color = match color_mode {
+                    ColorMode::Rgb => Pixel::new(
+                        self.add_noise(random, pixel.get_r()),
+                        self.add_noise(random, pixel.get_g()),
+                        self.add_noise(random, pixel.get_b()),
+                    ),
+                    ColorMode::Grayscale => Pixel::grayscale(self.add_noise(random, pixel.get_r())),
+                };
+
+                image[y * image_width + x] = context.constrain_color(color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn setStrength_nonPositiveStrength_errorReturned() {
+        let mut mutator = NoiseMutator::default();
+
+        assert!(mutator.set_strength(0f64).is_err());
+        assert!(mutator.set_strength(-1f64).is_err());
+        assert!(mutator.set_strength(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn mutateGrayscale_anyRegion_pixelsStayGrayscaleAndNoiseIsCentered() {
+        let mut mutator = NoiseMutator::default();
+        mutator.set_strength(10f64).unwrap();
+        let mut image = Image::blank(20, 20, &Pixel::grayscale(128));
+
+        for _ in 0..20 {
+            mutator.mutate_grayscale(&mut image);
+        }
+
+        assert!(image
+            .pixels()
+            .iter()
+            .all(|pixel| pixel.get_r() == pixel.get_g() && pixel.get_g() == pixel.get_b()));
+        assert!(image.pixels().iter().any(|pixel| pixel.get_r() != 128));
+
+        let mean = image
+            .pixels()
+            .iter()
+            .map(|pixel| f64::from(pixel.get_r()))
+            .sum::<f64>()
+            / 400f64;
+        assert!((mean - 128f64).abs() < 5f64);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{geometry::Point, models::Pixel};
+
+use super::BlendMode;
+
+/// Describes how the color changes across the shape.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Gradient {
+    Flat,
+    Linear { start: Point, end: Point },
+    Radial { center: Point, radius: f64 },
+}
+
+/// Colors and opacity of a single shape.
+#[derive(Debug, Clone)]
+pub(crate) struct Paint {
+    gradient: Gradient,
+    /// Colors of the gradient with their offsets in range `[0, 1]`, sorted by the offset.
+    stops: Vec<(f64, Pixel)>,
+    opacity: f64,
+    blend_mode: BlendMode,
+}
+
+impl Paint {
+    /// Creates a paint which fills shapes with a single color.
+    #[must_use]
+    pub fn flat(color: Pixel, opacity: f64) -> Self {
+        Self {
+            gradient: Gradient::Flat,
+            stops: vec![(0f64, color)],
+            opacity,
+            blend_mode: BlendMode::Normal,
+        }
+    }
+
+    /// Creates a paint which fills shapes with a gradient. `stops` must be sorted by their offsets.
+    #[must_use]
+    pub fn gradient(gradient: Gradient, stops: Vec<(f64, Pixel)>, opacity: f64) -> Self {
+        assert!(!stops.is_empty(), "Gradient must have at least one color.");
+
+        Self {
+            gradient,
+            stops,
+            opacity,
+            blend_mode: BlendMode::Normal,
+        }
+    }
+
+    /// Returns the position on the gradient of the given point in range `[0, 1]`.
+    fn get_offset(&self, x: f64, y: f64) -> f64 {
+        match &self.gradient {
+            Gradient::Flat => 0f64,
+            Gradient::Linear { start, end } => {
+                let (dx, dy) = (end.x - start.x, end.y - start.y);
+                let length_squared = dx * dx + dy * dy;
+
+                if length_squared > 0f64 {
+                    (((x - start.x) * dx + (y - start.y) * dy) / length_squared).clamp(0f64, 1f64)
+                } else {
+                    0f64
+                }
+            }
+            Gradient::Radial { center, radius } => {
+                if *radius > 0f64 {
+                    ((x - center.x).hypot(y - center.y) / radius).min(1f64)
+                } else {
+                    0f64
+                }
+            }
+        }
+    }
+
+    /// Returns the opacity of the shape in range `[0, 1]`.
+    pub fn get_opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    /// Sets how colors of the paint are combined with colors underneath the shape. The mode must not be
+    /// [BlendMode::Random].
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Returns the number of colors of the paint.
+    pub fn stop_count(&self) -> usize {
+        self.stops.len()
+    }
+
+    /// Replaces the color of the stop at `index`.
+    pub fn set_stop_color(&mut self, index: usize, color: Pixel) {
+        self.stops[index].1 = color;
+    }
+
+    /// Returns indices of the two stops between which the center of the given pixel lies, together with the weight of
+    /// the second one. The color of the pixel is interpolated between the colors of these stops.
+    pub fn get_stop_weights(&self, x: usize, y: usize) -> (usize, usize, f64) {
+        let offset = self.get_offset(x as f64 + 0.5, y as f64 + 0.5);
+
+        let next = self
+            .stops
+            .iter()
+            .position(|(stop, _)| *stop >= offset)
+            .unwrap_or(self.stops.len() - 1);
+
+        if next == 0 {
+            return (0, 0, 0f64);
+        }
+
+        let previous_offset = self.stops[next - 1].0;
+        let next_offset = self.stops[next].0;
+
+        if next_offset > previous_offset {
+            (
+                next - 1,
+                next,
+                (offset - previous_offset) / (next_offset - previous_offset),
+            )
+        } else {
+            (next - 1, next, 0f64)
+        }
+    }
+
+    /// Returns the color of the shape at the center of the given pixel.
+    pub fn get_color(&self, x: usize, y: usize) -> Pixel {
+        let (previous, next, weight) = self.get_stop_weights(x, y);
+
+        let mut color = self.stops[previous].1.clone();
+        if weight > 0f64 {
+            color.blend(&self.stops[next].1, weight);
+        }
+
+        color
+    }
+
+    /// Paints the pixel `(x, y)`, where `coverage` in range `[0, 1]` describes how much of the pixel lies inside the
+    /// shape.
+    pub fn apply(&self, pixel: &mut Pixel, x: usize, y: usize, coverage: f64) {
+        match (self.blend_mode, &self.gradient) {
+            (BlendMode::Normal, Gradient::Flat) => {
+                pixel.blend(&self.stops[0].1, self.opacity * coverage)
+            }
+            (BlendMode::Normal, _) => pixel.blend(&self.get_color(x, y), self.opacity * coverage),
+            (blend_mode, _) => {
+                let color = blend_mode.combine(pixel, &self.get_color(x, y));
+                pixel.blend(&color, self.opacity * coverage);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn getColor_linearGradient_colorsInterpolatedAlongAxis() {
+        let paint = Paint::gradient(
+            Gradient::Linear {
+                start: Point::new(0.5, 0.5),
+                end: Point::new(10.5, 0.5),
+            },
+            vec![(0f64, Pixel::grayscale(0)), (1f64, Pixel::grayscale(200))],
+            1f64,
+        );
+
+        assert_eq!(Pixel::grayscale(0), paint.get_color(0, 0));
+        assert_eq!(Pixel::grayscale(100), paint.get_color(5, 7));
+        assert_eq!(Pixel::grayscale(200), paint.get_color(10, 3));
+        assert_eq!(Pixel::grayscale(200), paint.get_color(20, 0));
+    }
+
+    #[test]
+    fn getColor_radialGradientWithThreeStops_middleStopReached() {
+        let paint = Paint::gradient(
+            Gradient::Radial {
+                center: Point::new(0.5, 0.5),
+                radius: 10f64,
+            },
+            vec![
+                (0f64, Pixel::new(0, 0, 0)),
+                (0.5, Pixel::new(255, 0, 0)),
+                (1f64, Pixel::new(255, 255, 255)),
+            ],
+            1f64,
+        );
+
+        assert_eq!(Pixel::new(0, 0, 0), paint.get_color(0, 0));
+        assert_eq!(Pixel::new(255, 0, 0), paint.get_color(0, 5));
+        assert_eq!(Pixel::new(255, 255, 255), paint.get_color(10, 0));
+    }
+
+    #[test]
+    fn apply_flatPaintWithHalfOpacity_colorBlended() {
+        let paint = Paint::flat(Pixel::grayscale(200), 0.5);
+        let mut pixel = Pixel::grayscale(0);
+
+        paint.apply(&mut pixel, 0, 0, 1f64);
+
+        assert_eq!(Pixel::grayscale(100), pixel);
+    }
+
+    #[test]
+    fn apply_multiplyBlendMode_colorsMultiplied() {
+        let mut paint = Paint::flat(Pixel::grayscale(51), 1f64);
+        paint.set_blend_mode(BlendMode::Multiply);
+        let mut pixel = Pixel::grayscale(200);
+
+        paint.apply(&mut pixel, 0, 0, 1f64);
+
+        assert_eq!(Pixel::grayscale(40), pixel);
+    }
+}
@@ -0,0 +1,17 @@
+/// This enum specifies where shapes generated by mutators are placed.
+///
+/// Placements other than [Placement::Uniform] need to know the error map, i.e. they only take effect when mutators are
+/// given a [MutationContext] with the error map set. Environments compute it whenever one of their mutators uses such a
+/// placement. Otherwise shapes are placed uniformly.
+///
+/// [MutationContext]: crate::mutators::MutationContext
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Every point of the image is equally likely to be the position of a shape.
+    #[default]
+    Uniform,
+
+    /// Shapes are placed with probability proportional to the error of the best specimen from the previous generation,
+    /// so that mutations concentrate on parts of the image which are still wrong.
+    ErrorGuided,
+}
@@ -0,0 +1,16 @@
+/// This enum specifies different kinds of polygons generated by [PolygonMutator].
+///
+/// [PolygonMutator]: crate::mutators::PolygonMutator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonKind {
+    /// Generates convex polygons, with vertices lying on a circle.
+    Convex,
+
+    /// Generates star-shaped polygons, i.e. polygons in which every vertex is visible from the center. Edges never
+    /// intersect each other.
+    Star,
+
+    /// Generates polygons with randomly placed vertices. Edges may intersect each other, in which case the polygon is
+    /// filled according to the even-odd rule.
+    Free,
+}
@@ -0,0 +1,230 @@
+use std::f64::consts::TAU;
+
+use anyhow::{Error, Result};
+
+use crate::{
+    geometry::{Point, Polygon},
+    models::Image,
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::{MutationContext, Mutator, PolygonKind, ShapeStyle};
+
+/// This mutator mutates the given specimen by generating a random filled polygon with random background color.
+///
+/// By default it generates convex polygons with 3 to 8 vertices.
+#[derive(Debug)]
+pub struct PolygonMutator {
+    style: ShapeStyle,
+    kind: PolygonKind,
+    min_vertices: usize,
+    max_vertices: usize,
+}
+
+impl PolygonMutator {
+    /// Creates a new mutator which paints polygons with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the kind of generated polygons.
+    ///
+    /// This property is optional. The default value is: [PolygonKind::Convex].
+    pub fn set_kind(&mut self, kind: PolygonKind) {
+        self.kind = kind;
+    }
+
+    /// Sets the inclusive range from which the number of vertices of each polygon is picked.
+    ///
+    /// This method returns an error if `min_vertices` is smaller than 3 or greater than `max_vertices`.
+    ///
+    /// This property is optional. The default values are: 3 for `min_vertices` and 8 for `max_vertices`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use franklin::mutators::PolygonMutator;
+    ///
+    /// let mut mutator = PolygonMutator::default();
+    ///
+    /// mutator.set_vertex_count(4, 6).unwrap();
+    /// ```
+    pub fn set_vertex_count(&mut self, min_vertices: usize, max_vertices: usize) -> Result<()> {
+        if min_vertices < 3 {
+            return Err(Error::msg("Polygon must have at least 3 vertices."));
+        }
+
+        if min_vertices > max_vertices {
+            return Err(Error::msg(
+                "Minimal number of vertices cannot be greater than the maximal number.",
+            ));
+        }
+
+        self.min_vertices = min_vertices;
+        self.max_vertices = max_vertices;
+
+        Ok(())
+    }
+
+    fn get_random_polygon(
+        &self,
+        random: &mut Random,
+        image: &Image,
+        position: Point,
+        max_size: f64,
+    ) -> Polygon {
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+
+        let center_x = position.x;
+        let center_y = position.y;
+        let radius = random.get_random(
+            1f64,
+            (image_width.min(image_height) / 2f64 * max_size).max(2f64),
+        );
+
+        let vertex_count = random.get_random(self.min_vertices, self.max_vertices + 1);
+
+        let vertices = match self.kind {
+            PolygonKind::Convex | PolygonKind::Star => {
+                // Vertices sorted by their angle never produce intersecting edges.
+                let mut angles = (0..vertex_count)
+                    .map(|_| random.get_random(0f64, TAU))
+                    .collect::<Vec<f64>>();
+                angles.sort_by(f64::total_cmp);
+
+                angles
+                    .into_iter()
+                    .map(|angle| {
+                        let distance = match self.kind {
+                            PolygonKind::Star => random.get_random(radius / 5f64, radius),
+                            _ => radius,
+                        };
+
+                        Point::new(
+                            center_x + distance * angle.cos(),
+                            center_y + distance * angle.sin(),
+                        )
+                    })
+                    .collect()
+            }
+            PolygonKind::Free => (0..vertex_count)
+                .map(|_| {
+                    Point::new(
+                        random.get_random(center_x - radius, center_x + radius),
+                        random.get_random(center_y - radius, center_y + radius),
+                    )
+                })
+                .collect(),
+        };
+
+        Polygon::new(vertices)
+    }
+}
+
+impl Default for PolygonMutator {
+    fn default() -> Self {
+        Self {
+            style: ShapeStyle::default(),
+            kind: PolygonKind::Convex,
+            min_vertices: 3,
+            max_vertices: 8,
+        }
+    }
+}
+
+impl Mutator for PolygonMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let max_size = self.style.get_max_size(context);
+        let position = self.style.get_random_position(context, image);
+        let polygon = self.get_random_polygon(context.get_random(), image, position, max_size);
+
+        self.style.draw_closed(&polygon, image, context);
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::models::Pixel;
+
+    fn get_random_polygons(mutator: &PolygonMutator) -> Vec<Polygon> {
+        let image = Image::blank(20, 30, &Pixel::white());
+        let mut random = Random::default();
+
+        (0..1000)
+            .map(|_| mutator.get_random_polygon(&mut random, &image, Point::new(15f64, 10f64), 0.5))
+            .collect()
+    }
+
+    #[test]
+    fn getRandomPolygon_vertexCountSet_vertexCountWithinRange() {
+        let mut mutator = PolygonMutator::default();
+        mutator.set_vertex_count(4, 6).unwrap();
+
+        let counts = get_random_polygons(&mutator)
+            .iter()
+            .map(|polygon| polygon.edges().count())
+            .collect::<Vec<usize>>();
+
+        assert!(counts.iter().all(|count| (4..=6).contains(count)));
+        assert!((4..=6).all(|count| counts.contains(&count)));
+    }
+
+    #[test]
+    fn getRandomPolygon_convexKind_polygonConvex() {
+        let mut mutator = PolygonMutator::default();
+        mutator.set_kind(PolygonKind::Convex);
+
+        for polygon in get_random_polygons(&mutator) {
+            let vertices = polygon.edges().map(|(start, _)| start).collect::<Vec<_>>();
+
+            // Every turn between consecutive edges of a convex polygon goes in the same direction.
+            let turns = (0..vertices.len())
+                .map(|index| {
+                    let a = vertices[index];
+                    let b = vertices[(index + 1) % vertices.len()];
+                    let c = vertices[(index + 2) % vertices.len()];
+
+                    (b.x - a.x) * (c.y - b.y) - (b.y - a.y) * (c.x - b.x)
+                })
+                .collect::<Vec<f64>>();
+
+            assert!(
+                turns.iter().all(|&turn| turn >= -1e-9) || turns.iter().all(|&turn| turn <= 1e-9)
+            );
+        }
+    }
+
+    #[test]
+    fn setVertexCount_invalidRange_errorReturned() {
+        let mut mutator = PolygonMutator::default();
+
+        assert!(mutator.set_vertex_count(2, 5).is_err());
+        assert!(mutator.set_vertex_count(6, 5).is_err());
+    }
+}
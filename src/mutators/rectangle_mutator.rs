@@ -1,73 +1,144 @@
-use crate::{models::Image, util::Random};
+use crate::{geometry::Rectangle, models::Image, ColorMode, RunConfiguration};
 
-use super::Mutator;
-
-struct RandomRectangle {
-    x: usize,
-    y: usize,
-    width: usize,
-    height: usize,
-}
+use super::{MutationContext, Mutator, ShapeStyle};
 
 /// This mutator mutates the given specimen by generating a random rectangle with random background color.
+///
+/// Rectangles may partially lie outside of the specimen, in which case they are clipped to its edges. Their width and
+/// height are picked uniformly, and their centers are spread so that every pixel is equally likely to be covered.
 #[derive(Debug, Default)]
-pub struct RectangleMutator;
+pub struct RectangleMutator {
+    style: ShapeStyle,
+}
 
 impl RectangleMutator {
-    fn get_random_rectangle(&self, random: &mut Random, image: &Image) -> RandomRectangle {
-        let image_width = image.width();
-        let image_height = image.height();
+    /// Creates a new mutator which paints rectangles with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self { style }
+    }
+
+    fn get_random_rectangle(&self, context: &mut MutationContext, image: &Image) -> Rectangle {
+        let max_size = self.style.get_max_size(context);
 
-        let x = random.get_random(0usize, image_width);
-        let y = random.get_random(0usize, image_height);
+        let max_width = ((image.width() as f64 * max_size).ceil() as usize).max(1);
+        let max_height = ((image.height() as f64 * max_size).ceil() as usize).max(1);
 
-        let width = random.get_random(0usize, image_width - x) + 1;
-        let height = random.get_random(0usize, image_height - y) + 1;
+        let width = context.get_random().get_random(1, max_width + 1);
+        let height = context.get_random().get_random(1, max_height + 1);
 
-        RandomRectangle {
-            x,
-            y,
-            width,
-            height,
+        // Edges are aligned to pixels. Uniformly placed rectangles start anywhere from `1 - width` to the last column,
+        // i.e. every pixel is covered by `width` out of all possible positions.
+        let margin_x = (width - 1) as f64 / 2f64;
+        let margin_y = (height - 1) as f64 / 2f64;
+        let center = self
+            .style
+            .get_random_center(context, image, margin_x, margin_y);
+
+        let min_x = (center.x - margin_x).floor();
+        let min_y = (center.y - margin_y).floor();
+
+        Rectangle {
+            min_x,
+            min_y,
+            max_x: min_x + width as f64,
+            max_y: min_y + height as f64,
         }
     }
 }
 
 impl Mutator for RectangleMutator {
     fn mutate_rgb(&self, image: &mut Image) {
-        let mut random = Random::default();
-
-        let rect = self.get_random_rectangle(&mut random, image);
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
 
-        let image_width = image.width();
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
 
-        let r = random.get_random(0u8, 255);
-        let g = random.get_random(0u8, 255);
-        let b = random.get_random(0u8, 255);
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let rectangle = self.get_random_rectangle(context, image);
 
-        for i in rect.x..(rect.width + rect.x) {
-            for j in rect.y..(rect.height + rect.y) {
-                let pixel = &mut image[j * image_width + i];
-                pixel.r(r);
-                pixel.g(g);
-                pixel.b(b);
-            }
-        }
+        self.style.draw_closed(&rectangle, image, context);
     }
 
-    fn mutate_grayscale(&self, image: &mut Image) {
-        let mut random = Random::default();
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
 
-        let rect = self.get_random_rectangle(&mut random, image);
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{geometry::rasterize, models::Pixel, mutators::SizeSchedule};
+
+    #[test]
+    fn mutate_contextsWithSameSeeds_sameImagesProduced() {
+        let mutator = RectangleMutator::default();
+        let configuration = RunConfiguration::default();
+        let mut first = Image::blank(20, 20, &Pixel::white());
+        let mut second = Image::blank(20, 20, &Pixel::white());
+
+        for seed in 0..10 {
+            let mut context = MutationContext::new(&configuration);
+            context.set_seed(seed);
+            mutator.mutate(&mut first, &mut context);
+
+            let mut context = MutationContext::new(&configuration);
+            context.set_seed(seed);
+            mutator.mutate(&mut second, &mut context);
+        }
 
-        let image_width = image.width();
+        assert_eq!(first.pixels(), second.pixels());
+    }
 
-        let grayscale = random.get_random(0u8, 255);
+    #[test]
+    fn getRandomRectangle_smallMaxSize_rectanglesLimitedToFractionOfImage() {
+        let mut style = ShapeStyle::default();
+        style.set_size_schedule(SizeSchedule::Constant { size: 0.1 });
+        let mutator = RectangleMutator::new(style);
+        let image = Image::blank(40, 20, &Pixel::white());
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+
+        for _ in 0..1000 {
+            let rectangle = mutator.get_random_rectangle(&mut context, &image);
+
+            assert!(rectangle.max_x - rectangle.min_x <= 2f64);
+            assert!(rectangle.max_y - rectangle.min_y <= 4f64);
+            assert!(rectangle.max_x > 0f64 && rectangle.min_x < 20f64);
+            assert!(rectangle.max_y > 0f64 && rectangle.min_y < 40f64);
+        }
+    }
 
-        for i in rect.x..(rect.width + rect.x) {
-            for j in rect.y..(rect.height + rect.y) {
-                image[j * image_width + i].set_grayscale(grayscale);
-            }
+    #[test]
+    fn getRandomRectangle_manyRectangles_allPixelsCoveredEqually() {
+        let mut style = ShapeStyle::default();
+        style.set_size_schedule(SizeSchedule::Constant { size: 0.5 });
+        let mutator = RectangleMutator::new(style);
+        let (width, height) = (8, 6);
+        let image = Image::blank(height, width, &Pixel::white());
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_seed(0);
+        let mut counts = vec![0; width * height];
+
+        for _ in 0..100_000 {
+            let rectangle = mutator.get_random_rectangle(&mut context, &image);
+            rasterize(&rectangle, width, height, false, |x, y, _| {
+                counts[y * width + x] += 1
+            });
         }
+
+        let min = *counts.iter().min().unwrap() as f64;
+        let max = *counts.iter().max().unwrap() as f64;
+        assert!(max / min < 1.15, "Coverage is not uniform: {:?}", counts);
     }
 }
@@ -0,0 +1,73 @@
+use crate::models::Image;
+
+use super::{MutationContext, SizeSchedule};
+
+/// Rectangular part of an image, in which raster mutators change pixels.
+///
+/// Columns `min_x..max_x` and rows `min_y..max_y` belong to the region. Regions are always clipped to the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Region {
+    pub min_x: usize,
+    pub min_y: usize,
+    pub max_x: usize,
+    pub max_y: usize,
+}
+
+fn get_random_span(context: &mut MutationContext, length: usize, max_size: f64) -> (usize, usize) {
+    let max_extent = ((length as f64 * max_size).ceil() as i64).max(1);
+    let extent = context.get_random().get_random(1, max_extent + 1);
+
+    // Regions start anywhere from `1 - extent` to the last pixel, so every pixel is equally likely to be included.
+    let start = context.get_random().get_random(1 - extent, length as i64);
+
+    (
+        start.max(0) as usize,
+        (start + extent).min(length as i64) as usize,
+    )
+}
+
+impl Region {
+    /// Returns a random non-empty region of `image`, whose size is limited by `size_schedule`.
+    pub fn random(
+        context: &mut MutationContext,
+        image: &Image,
+        size_schedule: &SizeSchedule,
+    ) -> Self {
+        let max_size =
+            size_schedule.get_max_size(context.get_generation(), context.get_stalled_generations());
+
+        let (min_x, max_x) = get_random_span(context, image.width(), max_size);
+        let (min_y, max_y) = get_random_span(context, image.height(), max_size);
+
+        Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{models::Pixel, RunConfiguration};
+
+    #[test]
+    fn random_anyRegion_regionNotEmptyAndWithinImage() {
+        let image = Image::blank(10, 20, &Pixel::white());
+        let schedule = SizeSchedule::Constant { size: 0.5 };
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+
+        for _ in 0..1000 {
+            let region = Region::random(&mut context, &image, &schedule);
+
+            assert!(region.min_x < region.max_x && region.max_x <= 20);
+            assert!(region.min_y < region.max_y && region.max_y <= 10);
+            assert!(region.max_x - region.min_x <= 10);
+            assert!(region.max_y - region.min_y <= 5);
+        }
+    }
+}
@@ -0,0 +1,677 @@
+use anyhow::{Error, Result};
+
+use crate::{
+    geometry::{rasterize, Bounds, Outline, Point, Shape},
+    models::{Image, Pixel},
+    util::Random,
+    ColorMode,
+};
+
+use super::{
+    paint::{Gradient, Paint},
+    Alpha, BlendMode, ColorStrategy, Fill, MutationContext, Placement, SizeSchedule,
+};
+
+fn get_random_point(random: &mut Random, bounds: &Bounds) -> Point {
+    let get_random_coordinate = |random: &mut Random, min: f64, max: f64| {
+        if min < max {
+            random.get_random(min, max)
+        } else {
+            min
+        }
+    };
+
+    Point::new(
+        get_random_coordinate(random, bounds.min_x, bounds.max_x),
+        get_random_coordinate(random, bounds.min_y, bounds.max_y),
+    )
+}
+
+fn get_channels(pixel: &Pixel) -> [f64; 3] {
+    [
+        f64::from(pixel.get_r()),
+        f64::from(pixel.get_g()),
+        f64::from(pixel.get_b()),
+    ]
+}
+
+/// Replaces colors of `paint` with colors fitted to the target pixels covered by `footprint`.
+///
+/// Every pixel contributes to the two gradient stops it is interpolated from, proportionally to their weights. For flat
+/// paints this gives exactly the mean, or the least-squares optimal color.
+fn fit_colors(
+    paint: &mut Paint,
+    strategy: ColorStrategy,
+    footprint: &[(usize, usize, f64)],
+    canvas: &Image,
+    target: &Image,
+    context: &MutationContext,
+) {
+    let stop_count = paint.stop_count();
+    let mut numerators = vec![[0f64; 3]; stop_count];
+    let mut denominators = vec![0f64; stop_count];
+
+    let opacity = paint.get_opacity();
+    let image_width = canvas.width();
+
+    for &(x, y, coverage) in footprint {
+        let index = y * image_width + x;
+        let alpha = opacity * coverage;
+        let target = get_channels(&target[index]);
+        let current = get_channels(&canvas[index]);
+
+        // The pixel ends up as `current + alpha * (color - current)`, hence the least-squares optimal color is the
+        // mean of `(target - (1 - alpha) * current) / alpha`, weighted by `alpha^2`.
+        let (weight, scale, sample) = match strategy {
+            ColorStrategy::LeastSquares => (
+                alpha,
+                alpha,
+                [0, 1, 2].map(|channel| target[channel] - (1f64 - alpha) * current[channel]),
+            ),
+            _ => (coverage, 1f64, target),
+        };
+
+        let (previous, next, next_weight) = paint.get_stop_weights(x, y);
+
+        for (stop, influence) in [(previous, 1f64 - next_weight), (next, next_weight)] {
+            if influence > 0f64 {
+                for channel in 0..3 {
+                    numerators[stop][channel] += weight * influence * sample[channel];
+                }
+                denominators[stop] += weight * influence * scale;
+            }
+        }
+    }
+
+    for stop in 0..stop_count {
+        if denominators[stop] > 0f64 {
+            let [r, g, b] = numerators[stop]
+                .map(|sum| (sum / denominators[stop]).round().clamp(0f64, 255f64) as u8);
+
+            let color = match context.get_color_mode() {
+                ColorMode::Rgb => Pixel::new(r, g, b),
+                ColorMode::Grayscale => Pixel::grayscale(r),
+            };
+
+            paint.set_stop_color(stop, color);
+        }
+    }
+}
+
+/// This struct describes how shapes generated by mutators are painted onto specimens.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::mutators::{Alpha, ShapeStyle, TriangleMutator};
+///
+/// let mut style = ShapeStyle::default();
+/// style.set_alpha(Alpha::Random { min: 64, max: 192 });
+///
+/// let mutator = TriangleMutator::new(style);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ShapeStyle {
+    alpha: Alpha,
+    anti_aliasing: bool,
+    fill: Fill,
+    color_strategy: ColorStrategy,
+    size_schedule: SizeSchedule,
+    placement: Placement,
+    outline: Option<(usize, usize)>,
+    blend_mode: Option<BlendMode>,
+}
+
+impl ShapeStyle {
+    /// Sets the alpha value of generated shapes.
+    ///
+    /// This property is optional. The default value is: `Alpha::Fixed(255)`, i.e. shapes are fully opaque.
+    pub fn set_alpha(&mut self, alpha: Alpha) {
+        self.alpha = alpha;
+    }
+
+    /// Returns the alpha value of generated shapes.
+    pub fn get_alpha(&self) -> Alpha {
+        self.alpha
+    }
+
+    /// Enables or disables anti-aliasing of the edges of generated shapes.
+    ///
+    /// Anti-aliased edges are smoother, but slower to draw. This property is optional. The default value is: `false`.
+    pub fn set_anti_aliasing(&mut self, anti_aliasing: bool) {
+        self.anti_aliasing = anti_aliasing;
+    }
+
+    /// Returns whether the edges of generated shapes are anti-aliased.
+    pub fn get_anti_aliasing(&self) -> bool {
+        self.anti_aliasing
+    }
+
+    /// Sets how the interior of generated shapes is filled.
+    ///
+    /// Endpoints of gradients are picked at random within the bounding box of each shape. This property is optional.
+    /// The default value is: [Fill::Flat].
+    pub fn set_fill(&mut self, fill: Fill) {
+        self.fill = fill;
+    }
+
+    /// Returns how the interior of generated shapes is filled.
+    pub fn get_fill(&self) -> Fill {
+        self.fill
+    }
+
+    /// Sets how colors of generated shapes are chosen.
+    ///
+    /// This property is optional. The default value is: [ColorStrategy::Random].
+    pub fn set_color_strategy(&mut self, color_strategy: ColorStrategy) {
+        self.color_strategy = color_strategy;
+    }
+
+    /// Returns how colors of generated shapes are chosen.
+    pub fn get_color_strategy(&self) -> ColorStrategy {
+        self.color_strategy
+    }
+
+    /// Sets how the maximal size of generated shapes changes during the simulation.
+    ///
+    /// The schedule follows the generation of the [MutationContext] given to mutators. This property is optional. The
+    /// default value is: `SizeSchedule::Constant { size: 1.0 }`, i.e. shapes can span the whole image.
+    pub fn set_size_schedule(&mut self, size_schedule: SizeSchedule) {
+        self.size_schedule = size_schedule;
+    }
+
+    /// Returns how the maximal size of generated shapes changes during the simulation.
+    pub fn get_size_schedule(&self) -> SizeSchedule {
+        self.size_schedule
+    }
+
+    /// Returns the maximal size of shapes generated in the given context, as a fraction of the image size.
+    pub(crate) fn get_max_size(&self, context: &MutationContext) -> f64 {
+        self.size_schedule
+            .get_max_size(context.get_generation(), context.get_stalled_generations())
+    }
+
+    /// Sets where generated shapes are placed.
+    ///
+    /// This property is optional. The default value is: [Placement::Uniform].
+    pub fn set_placement(&mut self, placement: Placement) {
+        self.placement = placement;
+    }
+
+    /// Returns where generated shapes are placed.
+    pub fn get_placement(&self) -> Placement {
+        self.placement
+    }
+
+    /// Makes the Rectangle, Triangle, Circle and Polygon mutators draw only outlines of shapes, instead of filling
+    /// them. The width of each outline, in pixels, is picked from the given inclusive range, so equal values give a
+    /// fixed width.
+    ///
+    /// This method returns an error if `min_width` is zero or it's greater than `max_width`.
+    ///
+    /// This property is optional. By default shapes are filled.
+    pub fn set_outline(&mut self, min_width: usize, max_width: usize) -> Result<()> {
+        if min_width == 0 {
+            return Err(Error::msg("Outline width cannot be zero."));
+        }
+
+        if min_width > max_width {
+            return Err(Error::msg(
+                "Minimal width cannot be greater than the maximal width.",
+            ));
+        }
+
+        self.outline = Some((min_width, max_width));
+
+        Ok(())
+    }
+
+    /// Returns the inclusive range of widths of outlines, or `None` if shapes are filled.
+    pub fn get_outline(&self) -> Option<(usize, usize)> {
+        self.outline
+    }
+
+    /// Returns a random position of a shape generated in the given context, within `image`.
+    pub(crate) fn get_random_position(
+        &self,
+        context: &mut MutationContext,
+        image: &Image,
+    ) -> Point {
+        self.get_random_center(context, image, 0f64, 0f64)
+    }
+
+    /// Returns a random center of a shape generated in the given context, which extends `margin_x` and `margin_y`
+    /// pixels from its center.
+    ///
+    /// Uniformly placed centers may lie up to the margin away from `image`, so that every pixel, including the ones
+    /// along the edges, is equally likely to be covered by the shape.
+    pub(crate) fn get_random_center(
+        &self,
+        context: &mut MutationContext,
+        image: &Image,
+        margin_x: f64,
+        margin_y: f64,
+    ) -> Point {
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+
+        let pixel = match (self.placement, context.get_error_map()) {
+            (Placement::ErrorGuided, Some(error_map))
+                if (error_map.width(), error_map.height()) == (image.width(), image.height()) =>
+            {
+                error_map.get_random_pixel(context.get_random())
+            }
+            _ => None,
+        };
+
+        match pixel {
+            // The position is spread evenly over the area of the picked pixel.
+            Some((x, y)) => Point::new(
+                x as f64 + context.get_random().get_random(0f64, 1f64),
+                y as f64 + context.get_random().get_random(0f64, 1f64),
+            ),
+            None => Point::new(
+                context
+                    .get_random()
+                    .get_random(-margin_x, image_width + margin_x),
+                context
+                    .get_random()
+                    .get_random(-margin_y, image_height + margin_y),
+            ),
+        }
+    }
+
+    /// Paints `shape` onto `image` with a new paint, chosen according to this style.
+    ///
+    /// When the context has a palette, every painted pixel is snapped to the closest color of the palette after
+    /// compositing, so gradients, translucency, anti-aliased edges and blend modes never leave the palette.
+    pub(crate) fn draw<S>(&self, shape: &S, image: &mut Image, context: &mut MutationContext)
+    where
+        S: Shape + ?Sized,
+    {
+        let mut paint = self.get_paint(context, &shape.bounds());
+        paint.set_blend_mode(self.get_random_blend_mode(context));
+
+        let image_width = image.width();
+        let image_height = image.height();
+        let anti_aliasing = self.anti_aliasing;
+        let palette = context.get_palette();
+        let paint_pixel = |paint: &Paint, pixel: &mut Pixel, x: usize, y: usize, coverage: f64| {
+            paint.apply(pixel, x, y, coverage);
+
+            if let Some(palette) = palette {
+                *pixel = palette.get_closest(pixel).clone();
+            }
+        };
+
+        match (self.color_strategy, context.get_target()) {
+            (ColorStrategy::Random, _) | (_, None) => {
+                rasterize(
+                    shape,
+                    image_width,
+                    image_height,
+                    anti_aliasing,
+                    |x, y, coverage| {
+                        paint_pixel(&paint, &mut image[y * image_width + x], x, y, coverage)
+                    },
+                );
+            }
+            (strategy, Some(target)) => {
+                let mut footprint = Vec::new();
+                rasterize(
+                    shape,
+                    image_width,
+                    image_height,
+                    anti_aliasing,
+                    |x, y, coverage| footprint.push((x, y, coverage)),
+                );
+
+                fit_colors(&mut paint, strategy, &footprint, image, target, context);
+
+                for (x, y, coverage) in footprint {
+                    paint_pixel(&paint, &mut image[y * image_width + x], x, y, coverage);
+                }
+            }
+        }
+    }
+
+    /// Sets how colors of generated shapes are combined with colors of the specimen underneath them, overriding the
+    /// blend mode of the simulation.
+    ///
+    /// Color strategies other than [ColorStrategy::Random] fit colors as if shapes were blended normally. This property
+    /// is optional. By default the blend mode of the simulation is used, see [MutationContext::get_blend_mode].
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = Some(blend_mode);
+    }
+
+    /// Returns the blend mode of generated shapes, or `None` if the blend mode of the simulation is used.
+    pub fn get_blend_mode(&self) -> Option<BlendMode> {
+        self.blend_mode
+    }
+
+    /// Returns whether shapes drawn with this style need the error map, i.e. whether they're placed guided by it.
+    pub(crate) fn uses_error_map(&self) -> bool {
+        self.placement == Placement::ErrorGuided
+    }
+
+    /// Returns the blend mode of a new shape generated in the given context.
+    pub(crate) fn get_random_blend_mode(&self, context: &mut MutationContext) -> BlendMode {
+        self.blend_mode
+            .unwrap_or_else(|| context.get_blend_mode())
+            .resolve(context.get_random())
+    }
+
+    /// Paints closed `shape` onto `image`, either filled or as an outline, according to this style.
+    pub(crate) fn draw_closed<S>(&self, shape: &S, image: &mut Image, context: &mut MutationContext)
+    where
+        S: Shape + ?Sized,
+    {
+        match self.outline {
+            Some((min_width, max_width)) => {
+                let width = context.get_random().get_random(min_width, max_width + 1) as f64;
+                self.draw(&Outline { shape, width }, image, context);
+            }
+            None => self.draw(shape, image, context),
+        }
+    }
+
+    /// Returns a new random paint for the shape with the given bounding box.
+    fn get_paint(&self, context: &mut MutationContext, bounds: &Bounds) -> Paint {
+        let opacity = self.alpha.get_opacity(context.get_random());
+
+        let (gradient, stops) = match self.fill {
+            Fill::Flat => return Paint::flat(context.get_random_color(), opacity),
+            Fill::LinearGradient { stops } => {
+                let start = get_random_point(context.get_random(), bounds);
+                let end = get_random_point(context.get_random(), bounds);
+
+                (Gradient::Linear { start, end }, stops)
+            }
+            Fill::RadialGradient { stops } => {
+                let center = get_random_point(context.get_random(), bounds);
+                let radius = (bounds.max_x - bounds.min_x)
+                    .max(bounds.max_y - bounds.min_y)
+                    .max(1f64);
+
+                (
+                    Gradient::Radial {
+                        center,
+                        radius: context.get_random().get_random(0f64, radius) + 1f64,
+                    },
+                    stops,
+                )
+            }
+        };
+
+        // The first and the last stop always lie at the ends of the gradient.
+        let stops = stops.max(2);
+        let mut offsets = vec![0f64, 1f64];
+        offsets.extend((2..stops).map(|_| context.get_random().get_random(0f64, 1f64)));
+        offsets.sort_by(f64::total_cmp);
+
+        let stops = offsets
+            .into_iter()
+            .map(|offset| (offset, context.get_random_color()))
+            .collect();
+
+        Paint::gradient(gradient, stops, opacity)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{
+        geometry::Rectangle,
+        models::{ErrorMap, Palette},
+        RunConfiguration,
+    };
+
+    fn draw_half(style: &ShapeStyle, canvas: &mut Image, target: &Image) {
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 2f64,
+            max_y: 1f64,
+        };
+
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_target(target);
+
+        style.draw(&rectangle, canvas, &mut context);
+    }
+
+    fn get_target() -> Image {
+        let mut target = Image::blank(2, 2, &Pixel::white());
+        target[0] = Pixel::new(100, 0, 50);
+        target[1] = Pixel::new(200, 100, 150);
+
+        target
+    }
+
+    #[test]
+    fn draw_meanColorStrategy_shapePaintedWithMeanOfTarget() {
+        let mut style = ShapeStyle::default();
+        style.set_color_strategy(ColorStrategy::Mean);
+        let mut canvas = Image::blank(2, 2, &Pixel::white());
+
+        draw_half(&style, &mut canvas, &get_target());
+
+        assert_eq!(Pixel::new(150, 50, 100), canvas[0]);
+        assert_eq!(Pixel::new(150, 50, 100), canvas[1]);
+        assert_eq!(Pixel::white(), canvas[2]);
+    }
+
+    #[test]
+    fn draw_leastSquaresColorStrategyWithHalfAlpha_shapeCompensatesForCanvas() {
+        let mut style = ShapeStyle::default();
+        style.set_alpha(Alpha::Fixed(128));
+        style.set_color_strategy(ColorStrategy::LeastSquares);
+        let mut canvas = Image::blank(2, 2, &Pixel::grayscale(100));
+        let mut target = Image::blank(2, 2, &Pixel::grayscale(100));
+        target[0] = Pixel::grayscale(150);
+        target[1] = Pixel::grayscale(150);
+
+        draw_half(&style, &mut canvas, &target);
+
+        assert_eq!(Pixel::grayscale(150), canvas[0]);
+        assert_eq!(Pixel::grayscale(150), canvas[1]);
+    }
+
+    #[test]
+    fn draw_targetGuidedStrategyWithoutTarget_shapeStillPainted() {
+        let mut style = ShapeStyle::default();
+        style.set_color_strategy(ColorStrategy::Mean);
+        let mut canvas = Image::blank(2, 2, &Pixel::white());
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 2f64,
+            max_y: 2f64,
+        };
+
+        style.draw(
+            &rectangle,
+            &mut canvas,
+            &mut MutationContext::new(&RunConfiguration::default()),
+        );
+
+        assert!((1..4).all(|index| canvas[index] == canvas[0]));
+    }
+
+    #[test]
+    fn draw_paletteGiven_fittedColorSnappedToPalette() {
+        let mut style = ShapeStyle::default();
+        style.set_color_strategy(ColorStrategy::Mean);
+        let palette = Palette::new(vec![Pixel::new(0, 0, 0), Pixel::new(160, 60, 90)]).unwrap();
+        let target = get_target();
+        let mut canvas = Image::blank(2, 2, &Pixel::white());
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 2f64,
+            max_y: 1f64,
+        };
+
+        let mut configuration = RunConfiguration::default();
+        configuration.set_palette(palette);
+        let mut context = MutationContext::new(&configuration);
+        context.set_target(&target);
+        style.draw(&rectangle, &mut canvas, &mut context);
+
+        assert_eq!(Pixel::new(160, 60, 90), canvas[0]);
+        assert_eq!(Pixel::new(160, 60, 90), canvas[1]);
+    }
+
+    #[test]
+    fn draw_paletteGivenWithTranslucentGradient_allPixelsInPalette() {
+        let mut style = ShapeStyle::default();
+        style.set_fill(Fill::LinearGradient { stops: 2 });
+        style.set_alpha(Alpha::Fixed(128));
+        let palette = Palette::new(vec![Pixel::new(0, 0, 0), Pixel::new(200, 120, 40)]).unwrap();
+        let mut canvas = Image::blank(16, 16, &Pixel::new(0, 0, 0));
+        let rectangle = Rectangle {
+            min_x: 0.5f64,
+            min_y: 0.5f64,
+            max_x: 15.5f64,
+            max_y: 15.5f64,
+        };
+
+        let mut configuration = RunConfiguration::default();
+        configuration.set_palette(palette.clone());
+        style.draw(
+            &rectangle,
+            &mut canvas,
+            &mut MutationContext::new(&configuration),
+        );
+
+        assert!(canvas
+            .pixels()
+            .iter()
+            .all(|pixel| palette.get_closest(pixel) == pixel));
+    }
+
+    #[test]
+    fn draw_paletteGivenWithRandomStrategy_colorPickedFromPalette() {
+        let style = ShapeStyle::default();
+        let palette = Palette::new(vec![Pixel::new(12, 34, 56)]).unwrap();
+        let mut canvas = Image::blank(2, 2, &Pixel::white());
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 2f64,
+            max_y: 2f64,
+        };
+
+        let mut configuration = RunConfiguration::default();
+        configuration.set_palette(palette);
+        style.draw(
+            &rectangle,
+            &mut canvas,
+            &mut MutationContext::new(&configuration),
+        );
+
+        assert!(canvas
+            .pixels()
+            .iter()
+            .all(|pixel| *pixel == Pixel::new(12, 34, 56)));
+    }
+
+    #[test]
+    fn getRandomPosition_errorGuidedPlacement_positionsWithinWrongPixels() {
+        let mut style = ShapeStyle::default();
+        style.set_placement(Placement::ErrorGuided);
+        let target = get_target();
+        let canvas = Image::blank(2, 2, &Pixel::white());
+        let error_map = ErrorMap::new(&target, &canvas, ColorMode::Rgb);
+
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_error_map(&error_map);
+
+        for _ in 0..100 {
+            let position = style.get_random_position(&mut context, &canvas);
+
+            assert!((0f64..2f64).contains(&position.x));
+            assert!((0f64..1f64).contains(&position.y));
+        }
+    }
+
+    #[test]
+    fn getRandomPosition_errorGuidedPlacementWithoutErrorMap_positionsWithinImage() {
+        let mut style = ShapeStyle::default();
+        style.set_placement(Placement::ErrorGuided);
+        let canvas = Image::blank(2, 2, &Pixel::white());
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+
+        let positions = (0..100)
+            .map(|_| style.get_random_position(&mut context, &canvas))
+            .collect::<Vec<Point>>();
+
+        assert!(positions.iter().all(
+            |position| (0f64..2f64).contains(&position.x) && (0f64..2f64).contains(&position.y)
+        ));
+        assert!(positions.iter().any(|position| position.y >= 1f64));
+    }
+
+    #[test]
+    fn setOutline_invalidWidths_errorReturned() {
+        let mut style = ShapeStyle::default();
+
+        assert!(style.set_outline(0, 3).is_err());
+        assert!(style.set_outline(4, 3).is_err());
+        assert_eq!(None, style.get_outline());
+    }
+
+    #[test]
+    fn drawClosed_outlineSet_interiorNotPainted() {
+        let mut style = ShapeStyle::default();
+        style.set_outline(1, 1).unwrap();
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 5f64,
+            max_y: 5f64,
+        };
+        let mut canvas = Image::blank(5, 5, &Pixel::new(1, 2, 3));
+
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        while canvas[0] == Pixel::new(1, 2, 3) {
+            style.draw_closed(&rectangle, &mut canvas, &mut context);
+        }
+
+        for y in 1..4 {
+            for x in 1..4 {
+                assert_eq!(Pixel::new(1, 2, 3), canvas[y * 5 + x]);
+            }
+        }
+    }
+
+    #[test]
+    fn draw_blendModeOverridden_styleBlendModeUsed() {
+        let mut style = ShapeStyle::default();
+        style.set_color_strategy(ColorStrategy::Mean);
+        style.set_blend_mode(BlendMode::Add);
+        let target = Image::blank(1, 1, &Pixel::grayscale(30));
+        let rectangle = Rectangle {
+            min_x: 0f64,
+            min_y: 0f64,
+            max_x: 1f64,
+            max_y: 1f64,
+        };
+        let mut canvas = Image::blank(1, 1, &Pixel::grayscale(100));
+
+        let mut configuration = RunConfiguration::default();
+        configuration.set_blend_mode(BlendMode::Subtract);
+        let mut context = MutationContext::new(&configuration);
+        context.set_target(&target);
+        style.draw(&rectangle, &mut canvas, &mut context);
+
+        // Subtracting the fitted color would give 70 instead.
+        assert_eq!(Pixel::grayscale(130), canvas[0]);
+    }
+}
@@ -0,0 +1,122 @@
+/// This enum specifies how the maximal size of shapes generated by mutators changes during the simulation.
+///
+/// The size is a fraction of the largest shape a mutator could draw on the whole image, e.g. a rectangle spanning
+/// the whole image or a circle touching its edges. Early generations benefit from large shapes, which quickly lay out
+/// the composition, while later generations need small shapes to add detail without wiping out what's already there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SizeSchedule {
+    /// The maximal size of shapes never changes.
+    Constant {
+        /// Maximal size of shapes, in range `(0, 1]`.
+        size: f64,
+    },
+
+    /// The maximal size of shapes halves every `half_life` generations, until it reaches `minimum`.
+    Generations {
+        /// Maximal size of shapes in the first generation, in range `(0, 1]`.
+        initial: f64,
+
+        /// The lowest maximal size of shapes, in range `(0, 1]`.
+        minimum: f64,
+
+        /// Number of generations after which the maximal size of shapes is halved.
+        half_life: u32,
+    },
+
+    /// The maximal size of shapes halves every time `patience` more generations fail to improve the best specimen,
+    /// until it reaches `minimum`.
+    Plateau {
+        /// Maximal size of shapes in the first generation, in range `(0, 1]`.
+        initial: f64,
+
+        /// The lowest maximal size of shapes, in range `(0, 1]`.
+        minimum: f64,
+
+        /// Number of generations without improvement after which the maximal size of shapes is halved.
+        patience: u32,
+    },
+}
+
+impl SizeSchedule {
+    /// Returns the maximal size of shapes in range `(0, 1]`, after the given number of generations, out of which
+    /// `stalled_generations` didn't improve the best specimen.
+    pub(crate) fn get_max_size(&self, generation: u32, stalled_generations: u32) -> f64 {
+        let (initial, minimum, halvings) = match *self {
+            SizeSchedule::Constant { size } => (size, size, 0f64),
+            SizeSchedule::Generations {
+                initial,
+                minimum,
+                half_life,
+            } => (
+                initial,
+                minimum,
+                f64::from(generation) / f64::from(half_life.max(1)),
+            ),
+            SizeSchedule::Plateau {
+                initial,
+                minimum,
+                patience,
+            } => (
+                initial,
+                minimum,
+                f64::from(stalled_generations / patience.max(1)),
+            ),
+        };
+
+        let size = (initial * 0.5f64.powf(halvings)).max(minimum.min(initial));
+
+        if size.is_finite() {
+            size.clamp(f64::EPSILON, 1f64)
+        } else {
+            1f64
+        }
+    }
+}
+
+impl Default for SizeSchedule {
+    fn default() -> Self {
+        SizeSchedule::Constant { size: 1f64 }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn getMaxSize_defaultSchedule_fullSizeForever() {
+        let schedule = SizeSchedule::default();
+
+        assert_eq!(1f64, schedule.get_max_size(0, 0));
+        assert_eq!(1f64, schedule.get_max_size(100_000, 50_000));
+    }
+
+    #[test]
+    fn getMaxSize_generationsSchedule_sizeHalvedEveryHalfLife() {
+        let schedule = SizeSchedule::Generations {
+            initial: 0.8,
+            minimum: 0.1,
+            half_life: 100,
+        };
+
+        assert!((schedule.get_max_size(0, 0) - 0.8).abs() < 1e-9);
+        assert!((schedule.get_max_size(100, 0) - 0.4).abs() < 1e-9);
+        assert!((schedule.get_max_size(200, 100) - 0.2).abs() < 1e-9);
+        assert!((schedule.get_max_size(10_000, 0) - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn getMaxSize_plateauSchedule_sizeHalvedOnlyAfterStalledGenerations() {
+        let schedule = SizeSchedule::Plateau {
+            initial: 1f64,
+            minimum: 0.2,
+            patience: 10,
+        };
+
+        assert_eq!(1f64, schedule.get_max_size(1000, 9));
+        assert!((schedule.get_max_size(20, 10) - 0.5).abs() < 1e-9);
+        assert!((schedule.get_max_size(30, 25) - 0.25).abs() < 1e-9);
+        assert!((schedule.get_max_size(100, 100) - 0.2).abs() < 1e-9);
+    }
+}
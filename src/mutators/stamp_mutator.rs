@@ -0,0 +1,248 @@
+use std::f64::consts::TAU;
+
+use anyhow::{Error, Result};
+
+use crate::{
+    models::{Brush, Image, Pixel},
+    ColorMode, RunConfiguration,
+};
+
+use super::{MutationContext, Mutator, ShapeStyle};
+
+fn tint_channel(channel: u8, tint: u8) -> u8 {
+    (f64::from(channel) * f64::from(tint) / 255f64).round() as u8
+}
+
+/// This mutator mutates the given specimen by pasting a randomly scaled, rotated and tinted copy of one of its brushes.
+///
+/// Brushes masked by their luminance are painted with a random tint through the mask, like ink of a random color.
+/// Colors of other brushes are multiplied by the tint, so white parts of them take the color of the tint. The mask of
+/// the brush decides how much of each of its pixels is pasted. Alpha, blend mode, size schedule and placement
+/// of the style apply to stamps as well, while fill and color strategy don't.
+///
+/// # Examples
+///
+/// ```no_run
+/// use franklin::ImageReader;
+/// use franklin::mutators::{ShapeStyle, StampMutator};
+///
+/// let brushes = ImageReader::load_brushes("path/to/brushes").unwrap();
+///
+/// let mutator = StampMutator::new(ShapeStyle::default(), brushes).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct StampMutator {
+    style: ShapeStyle,
+    brushes: Vec<Brush>,
+    tinted: bool,
+}
+
+impl StampMutator {
+    /// Creates a new mutator which pastes the given brushes with the given style.
+    ///
+    /// Returns an error if there are no brushes.
+    pub fn new(style: ShapeStyle, brushes: Vec<Brush>) -> Result<Self> {
+        if brushes.is_empty() {
+            return Err(Error::msg("There must be at least one brush."));
+        }
+
+        Ok(Self {
+            style,
+            brushes,
+            tinted: true,
+        })
+    }
+
+    /// Sets whether stamps are tinted with random colors, instead of keeping the original colors of brushes.
+    ///
+    /// This property is optional. The default value is: `true`.
+    pub fn set_tinted(&mut self, tinted: bool) {
+        self.tinted = tinted;
+    }
+}
+
+impl Mutator for StampMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let image_width = image.width();
+        let image_height = image.height();
+
+        let brush = &self.brushes[context.get_random().get_random(0, self.brushes.len())];
+        let brush_width = brush.width() as f64;
+        let brush_height = brush.height() as f64;
+
+        // The size is the length of the longer side of the stamp, in pixels.
+        let max_size =
+            (image_width.min(image_height) as f64 * self.style.get_max_size(context)).max(2f64);
+        let size = context.get_random().get_random(1f64, max_size);
+        let scale = size / brush_width.max(brush_height);
+
+        let center = self.style.get_random_position(context, image);
+        let (sin, cos) = context.get_random().get_random(0f64, TAU).sin_cos();
+        let tint = self.tinted.then(|| context.get_random_color());
+        let opacity = self.style.get_alpha().get_opacity(context.get_random());
+        let blend_mode = self.style.get_random_blend_mode(context);
+
+        let reach = scale * brush_width.hypot(brush_height) / 2f64;
+        let min_x = (center.x - reach).floor().max(0f64) as usize;
+        let min_y = (center.y - reach).floor().max(0f64) as usize;
+        let max_x = ((center.x + reach).ceil().max(0f64) as usize).min(image_width);
+        let max_y = ((center.y + reach).ceil().max(0f64) as usize).min(image_height);
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                // Every pixel of the specimen takes the nearest pixel of the brush, rotated back and scaled.
+                let dx = x as f64 + 0.5 - center.x;
+                let dy = y as f64 + 0.5 - center.y;
+                let u = (dx * cos + dy * sin) / scale + brush_width / 2f64;
+                let v = (dy * cos - dx * sin) / scale + brush_height / 2f64;
+
+                if !(0f64..brush_width).contains(&u) || !(0f64..brush_height).contains(&v) {
+                    continue;
+                }
+
+                let (u, v) = (u as usize, v as usize);
+                let mask = brush.get_mask(u, v);
+                if mask <= 0f64 {
+                    continue;
+                }
+
+                let color = brush.get_color(u, v);
+                let color = match (&tint, brush.is_luminance_masked()) {
+                    (Some(tint), true) => tint.clone(),
+                    (Some(tint), false) => Pixel::new(
+                        tint_channel(color.get_r(), tint.get_r()),
+                        tint_channel(color.get_g(), tint.get_g()),
+                        tint_channel(color.get_b(), tint.get_b()),
+                    ),
+                    (None, _) => color.clone(),
+                };
+                let color = match context.get_color_mode() {
+                    ColorMode::Rgb => color,
+                    ColorMode::Grayscale => Pixel::grayscale(color.luminance().round() as u8),
+                };
+
+                let pixel = &mut image[y * image_width + x];
+                let color = blend_mode.combine(pixel, &color);
+                pixel.blend(&color, opacity * mask);
+                *pixel = context.constrain_color(pixel.clone());
+            }
+        }
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::{models::Palette, mutators::BlendMode};
+
+    #[test]
+    fn new_noBrushes_errorReturned() {
+        assert!(StampMutator::new(ShapeStyle::default(), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn mutate_tintedLuminanceBrush_tintPasted() {
+        let brush = Brush::from_luminance(Image::blank(4, 4, &Pixel::grayscale(0))).unwrap();
+        let mutator = StampMutator::new(ShapeStyle::default(), vec![brush]).unwrap();
+        let mut image = Image::blank(20, 20, &Pixel::white());
+
+        let configuration = RunConfiguration::default();
+        let mut context = MutationContext::new(&configuration);
+        context.set_seed(7);
+        mutator.mutate(&mut image, &mut context);
+
+        let pasted = image
+            .pixels()
+            .iter()
+            .filter(|pixel| **pixel != Pixel::white())
+            .collect::<Vec<_>>();
+
+        // Black parts of the brush take the color of the tint, instead of staying black.
+        assert!(!pasted.is_empty());
+        assert!(pasted.iter().all(|pixel| *pixel == pasted[0]));
+        assert_ne!(&Pixel::grayscale(0), pasted[0]);
+    }
+
+    #[test]
+    fn mutateRgb_transparentBrush_imageNotChanged() {
+        let brush = Brush::new(Image::blank(4, 4, &Pixel::grayscale(0)), vec![0; 16]).unwrap();
+        let mutator = StampMutator::new(ShapeStyle::default(), vec![brush]).unwrap();
+        let mut image = Image::blank(20, 20, &Pixel::white());
+
+        for _ in 0..20 {
+            mutator.mutate_rgb(&mut image);
+        }
+
+        assert!(image.pixels().iter().all(|pixel| *pixel == Pixel::white()));
+    }
+
+    #[test]
+    fn mutateRgb_notTinted_brushColorsKept() {
+        let brush =
+            Brush::new(Image::blank(4, 4, &Pixel::new(10, 200, 30)), vec![255; 16]).unwrap();
+        let mut mutator = StampMutator::new(ShapeStyle::default(), vec![brush]).unwrap();
+        mutator.set_tinted(false);
+        let mut image = Image::blank(20, 20, &Pixel::white());
+
+        for _ in 0..20 {
+            mutator.mutate_rgb(&mut image);
+        }
+
+        assert!(image
+            .pixels()
+            .iter()
+            .all(|pixel| *pixel == Pixel::white() || *pixel == Pixel::new(10, 200, 30)));
+    }
+
+    #[test]
+    fn mutate_paletteGivenWithAddBlendMode_combinedColorSnappedToPalette() {
+        let brush = Brush::new(Image::blank(4, 4, &Pixel::grayscale(100)), vec![255; 16]).unwrap();
+        let mut style = ShapeStyle::default();
+        style.set_blend_mode(BlendMode::Add);
+        let mut mutator = StampMutator::new(style, vec![brush]).unwrap();
+        mutator.set_tinted(false);
+        let palette = Palette::new(vec![
+            Pixel::grayscale(0),
+            Pixel::grayscale(90),
+            Pixel::grayscale(190),
+        ])
+        .unwrap();
+        let mut image = Image::blank(20, 20, &Pixel::grayscale(90));
+
+        let mut configuration = RunConfiguration::new(ColorMode::Grayscale);
+        configuration.set_palette(palette);
+        let mut context = MutationContext::new(&configuration);
+        while image
+            .pixels()
+            .iter()
+            .all(|pixel| *pixel == Pixel::grayscale(90))
+        {
+            mutator.mutate(&mut image, &mut context);
+        }
+
+        // 90 + 100 lies in the palette, while 90 + the color of the palette closest to 100 does not.
+        assert!(image
+            .pixels()
+            .iter()
+            .all(|pixel| *pixel == Pixel::grayscale(90) || *pixel == Pixel::grayscale(190)));
+    }
+}
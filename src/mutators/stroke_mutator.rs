@@ -0,0 +1,190 @@
+use std::f64::consts::{FRAC_PI_6, PI, TAU};
+
+use anyhow::{Error, Result};
+
+use crate::{
+    geometry::{Point, Stroke},
+    models::Image,
+    util::Random,
+    ColorMode, RunConfiguration,
+};
+
+use super::{gradient_field::GradientField, MutationContext, Mutator, ShapeStyle};
+
+/// This mutator mutates the given specimen by generating a random brush stroke with random color.
+///
+/// A stroke is a thick line, or a polyline, with round caps. By default strokes are 1 to 10 pixels wide, consist of a
+/// single segment and are randomly oriented.
+#[derive(Debug)]
+pub struct StrokeMutator {
+    style: ShapeStyle,
+    min_width: usize,
+    max_width: usize,
+    min_segments: usize,
+    max_segments: usize,
+    guide: Option<GradientField>,
+}
+
+impl StrokeMutator {
+    /// Creates a new mutator which paints strokes with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the inclusive range from which the width of each stroke, in pixels, is picked.
+    ///
+    /// This method returns an error if `min_width` is zero or it's greater than `max_width`.
+    ///
+    /// This property is optional. The default values are: 1 for `min_width` and 10 for `max_width`.
+    pub fn set_width(&mut self, min_width: usize, max_width: usize) -> Result<()> {
+        if min_width == 0 {
+            return Err(Error::msg("Stroke width cannot be zero."));
+        }
+
+        if min_width > max_width {
+            return Err(Error::msg(
+                "Minimal width cannot be greater than the maximal width.",
+            ));
+        }
+
+        self.min_width = min_width;
+        self.max_width = max_width;
+
+        Ok(())
+    }
+
+    /// Sets the inclusive range from which the number of segments of each stroke is picked.
+    ///
+    /// This method returns an error if `min_segments` is zero or it's greater than `max_segments`.
+    ///
+    /// This property is optional. The default values are: 1 for `min_segments` and 1 for `max_segments`.
+    pub fn set_segment_count(&mut self, min_segments: usize, max_segments: usize) -> Result<()> {
+        if min_segments == 0 {
+            return Err(Error::msg("Stroke must have at least one segment."));
+        }
+
+        if min_segments > max_segments {
+            return Err(Error::msg(
+                "Minimal number of segments cannot be greater than the maximal number.",
+            ));
+        }
+
+        self.min_segments = min_segments;
+        self.max_segments = max_segments;
+
+        Ok(())
+    }
+
+    /// Orients strokes along the edges of the given image, usually the source image.
+    ///
+    /// Strokes starting in flat areas of the image, where there's no edge to follow, are still randomly oriented.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use franklin::ImageReader;
+    /// use franklin::mutators::StrokeMutator;
+    ///
+    /// let image = ImageReader::load("path/to/image.png").unwrap();
+    ///
+    /// let mut mutator = StrokeMutator::default();
+    /// mutator.set_guide(&image);
+    /// ```
+    pub fn set_guide(&mut self, image: &Image) {
+        self.guide = Some(GradientField::new(image));
+    }
+
+    fn get_angle(&self, random: &mut Random, point: &Point, previous_angle: Option<f64>) -> f64 {
+        let guided_angle = self
+            .guide
+            .as_ref()
+            .and_then(|guide| guide.get_edge_angle(point.x, point.y));
+
+        match (guided_angle, previous_angle) {
+            // Edges have no sense, so the stroke keeps going in the direction closer to the previous one.
+            (Some(angle), Some(previous)) if (angle - previous).cos() < 0f64 => angle + PI,
+            (Some(angle), _) => angle,
+            (None, Some(previous)) => previous + random.get_random(-FRAC_PI_6, FRAC_PI_6),
+            (None, None) => random.get_random(0f64, TAU),
+        }
+    }
+
+    fn get_random_stroke(
+        &self,
+        random: &mut Random,
+        image: &Image,
+        position: Point,
+        max_size: f64,
+    ) -> Stroke {
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+
+        let width = random.get_random(self.min_width, self.max_width + 1) as f64;
+        let segments = random.get_random(self.min_segments, self.max_segments + 1);
+
+        let max_length = (image_width.min(image_height) / 2f64 * max_size).max(2f64);
+        let segment_length = random.get_random(1f64, max_length) / segments as f64;
+
+        let mut point = position;
+        let mut points = vec![point];
+        let mut angle = None;
+
+        for _ in 0..segments {
+            let next_angle = self.get_angle(random, &point, angle);
+
+            point = Point::new(
+                point.x + segment_length * next_angle.cos(),
+                point.y + segment_length * next_angle.sin(),
+            );
+            points.push(point);
+            angle = Some(next_angle);
+        }
+
+        Stroke::new(points, width)
+    }
+}
+
+impl Default for StrokeMutator {
+    fn default() -> Self {
+        Self {
+            style: ShapeStyle::default(),
+            min_width: 1,
+            max_width: 10,
+            min_segments: 1,
+            max_segments: 1,
+            guide: None,
+        }
+    }
+}
+
+impl Mutator for StrokeMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let max_size = self.style.get_max_size(context);
+        let position = self.style.get_random_position(context, image);
+        let stroke = self.get_random_stroke(context.get_random(), image, position, max_size);
+
+        self.style.draw(&stroke, image, context);
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
+    }
+}
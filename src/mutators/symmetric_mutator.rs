@@ -0,0 +1,143 @@
+use crate::{models::Image, ColorMode, RunConfiguration};
+
+use super::{symmetry::Transformation, MutationContext, Mutator, Symmetry};
+
+/// This mutator wraps any other mutator and mirrors every change it makes, according to the given symmetry.
+///
+/// Changed pixels are copied to their symmetric counterparts, so the inner mutator doesn't need to know anything about
+/// symmetry. Pixels changed by the inner mutator itself always keep their new colors.
+///
+/// # Examples
+///
+/// ```
+/// use franklin::mutators::{CircleMutator, Symmetry, SymmetricMutator};
+///
+/// let mutator = SymmetricMutator::new(Box::new(CircleMutator::default()), Symmetry::Rotational(6));
+/// ```
+pub struct SymmetricMutator {
+    mutator: Box<dyn Mutator + Send + Sync>,
+    transformations: Vec<Transformation>,
+}
+
+impl SymmetricMutator {
+    /// Creates a new mutator which mirrors changes made by `mutator` according to `symmetry`.
+    #[must_use]
+    pub fn new(mutator: Box<dyn Mutator + Send + Sync>, symmetry: Symmetry) -> Self {
+        Self {
+            mutator,
+            transformations: symmetry.transformations(),
+        }
+    }
+}
+
+impl Mutator for SymmetricMutator {
+    fn mutate_rgb(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
+    }
+
+    fn mutate_grayscale(&self, image: &mut Image) {
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
+
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let mut mutated = image.clone();
+        self.mutator.mutate(&mut mutated, context);
+
+        let changed = image
+            .pixels()
+            .iter()
+            .zip(mutated.pixels())
+            .map(|(before, after)| before != after)
+            .collect::<Vec<bool>>();
+
+        if !changed.contains(&true) {
+            return;
+        }
+
+        let image_width = image.width();
+        let image_height = image.height();
+
+        // Every pixel takes the color of the first changed pixel it's symmetric to, starting with itself.
+        for y in 0..image_height {
+            for x in 0..image_width {
+                let source = self
+                    .transformations
+                    .iter()
+                    .filter_map(|transformation| {
+                        transformation.apply(x, y, image_width, image_height)
+                    })
+                    .map(|(x, y)| y * image_width + x)
+                    .find(|&index| changed[index]);
+
+                if let Some(index) = source {
+                    image[y * image_width + x] = mutated[index].clone();
+                }
+            }
+        }
+    }
+
+    fn uses_error_map(&self) -> bool {
+        self.mutator.uses_error_map()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::models::Pixel;
+
+    struct PixelMutator(usize);
+
+    impl Mutator for PixelMutator {
+        fn mutate_rgb(&self, image: &mut Image) {
+            image[self.0] = Pixel::grayscale(0);
+        }
+
+        fn mutate_grayscale(&self, image: &mut Image) {
+            self.mutate_rgb(image);
+        }
+    }
+
+    fn get_black_pixels(image: &Image) -> Vec<usize> {
+        (0..image.pixels().len())
+            .filter(|&index| image[index] == Pixel::grayscale(0))
+            .collect()
+    }
+
+    #[test]
+    fn mutateRgb_horizontalSymmetry_changeMirrored() {
+        let mutator = SymmetricMutator::new(Box::new(PixelMutator(1)), Symmetry::Horizontal);
+        let mut image = Image::blank(2, 4, &Pixel::white());
+
+        mutator.mutate_rgb(&mut image);
+
+        assert_eq!(vec![1, 2], get_black_pixels(&image));
+    }
+
+    #[test]
+    fn mutateRgb_verticalSymmetry_changeMirrored() {
+        let mutator = SymmetricMutator::new(Box::new(PixelMutator(1)), Symmetry::Vertical);
+        let mut image = Image::blank(2, 4, &Pixel::white());
+
+        mutator.mutate_rgb(&mut image);
+
+        assert_eq!(vec![1, 5], get_black_pixels(&image));
+    }
+
+    #[test]
+    fn mutateRgb_rotationalSymmetry_changeRepeatedAroundCenter() {
+        let mutator = SymmetricMutator::new(Box::new(PixelMutator(0)), Symmetry::Rotational(4));
+        let mut image = Image::blank(3, 3, &Pixel::white());
+
+        mutator.mutate_rgb(&mut image);
+
+        assert_eq!(vec![0, 2, 6, 8], get_black_pixels(&image));
+    }
+}
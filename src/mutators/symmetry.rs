@@ -0,0 +1,191 @@
+use std::{f64::consts::TAU, str::FromStr};
+
+use anyhow::{Error, Result};
+
+/// This enum specifies the symmetry enforced by [SymmetricMutator].
+///
+/// [SymmetricMutator]: crate::mutators::SymmetricMutator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Every change is mirrored between the left and the right half of the image.
+    Horizontal,
+
+    /// Every change is mirrored between the top and the bottom half of the image.
+    Vertical,
+
+    /// Every change is mirrored both horizontally and vertically, i.e. it's repeated in all four quarters of the image.
+    Both,
+
+    /// Every change is repeated `n` times, rotated around the center of the image by multiples of `360 / n` degrees.
+    ///
+    /// Parsed symmetries have between 2 and 64 copies.
+    Rotational(u32),
+}
+
+/// The maximal number of copies of a rotational symmetry, which can be parsed.
+const MAX_ROTATIONAL_COPIES: u32 = 64;
+
+/// This enum represents a single transformation of a [Symmetry], which maps pixels onto their symmetric counterparts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Transformation {
+    Identity,
+    MirrorX,
+    MirrorY,
+    MirrorBoth,
+    Rotation { sin: f64, cos: f64 },
+}
+
+impl Transformation {
+    /// Returns the pixel which `(x, y)` is mapped to by this transformation, if it's within the image.
+    pub(crate) fn apply(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<(usize, usize)> {
+        match *self {
+            Transformation::Identity => Some((x, y)),
+            Transformation::MirrorX => Some((width - 1 - x, y)),
+            Transformation::MirrorY => Some((x, height - 1 - y)),
+            Transformation::MirrorBoth => Some((width - 1 - x, height - 1 - y)),
+            Transformation::Rotation { sin, cos } => {
+                // Pixels are rotated around the center of the image, by their centers.
+                let center_x = width as f64 / 2f64;
+                let center_y = height as f64 / 2f64;
+                let dx = x as f64 + 0.5 - center_x;
+                let dy = y as f64 + 0.5 - center_y;
+
+                let rotated_x = (center_x + dx * cos - dy * sin).floor();
+                let rotated_y = (center_y + dx * sin + dy * cos).floor();
+
+                if (0f64..width as f64).contains(&rotated_x)
+                    && (0f64..height as f64).contains(&rotated_y)
+                {
+                    Some((rotated_x as usize, rotated_y as usize))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl Symmetry {
+    /// Returns all transformations of this symmetry, one for every copy of a change. The first one is always the
+    /// identity.
+    pub(crate) fn transformations(&self) -> Vec<Transformation> {
+        match *self {
+            Symmetry::Horizontal => vec![Transformation::Identity, Transformation::MirrorX],
+            Symmetry::Vertical => vec![Transformation::Identity, Transformation::MirrorY],
+            Symmetry::Both => vec![
+                Transformation::Identity,
+                Transformation::MirrorX,
+                Transformation::MirrorY,
+                Transformation::MirrorBoth,
+            ],
+            Symmetry::Rotational(n) => {
+                let n = n.max(1);
+
+                std::iter::once(Transformation::Identity)
+                    .chain((1..n).map(|k| {
+                        let (sin, cos) = (TAU * f64::from(k) / f64::from(n)).sin_cos();
+                        Transformation::Rotation { sin, cos }
+                    }))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl FromStr for Symmetry {
+    type Err = Error;
+
+    /// Parses one of "Horizontal", "Vertical", "Both" or "Rotational:N" (e.g. "Rotational:6"), where N is between 2
+    /// and 64.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once(':') {
+            Some((name, n)) if name.trim() == "Rotational" => {
+                let n = n.trim().parse::<u32>()?;
+
+                if n < 2 {
+                    return Err(Error::msg(
+                        "Rotational symmetry must have at least 2 copies.",
+                    ));
+                }
+
+                if n > MAX_ROTATIONAL_COPIES {
+                    return Err(Error::msg(format!(
+                        "Rotational symmetry can have at most {} copies.",
+                        MAX_ROTATIONAL_COPIES
+                    )));
+                }
+
+                Ok(Symmetry::Rotational(n))
+            }
+            Some(_) => Err(Error::msg(format!("Unknown symmetry: {}.", s))),
+            None => match s.trim() {
+                "Horizontal" => Ok(Symmetry::Horizontal),
+                "Vertical" => Ok(Symmetry::Vertical),
+                "Both" => Ok(Symmetry::Both),
+                _ => Err(Error::msg(format!("Unknown symmetry: {}.", s))),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fromStr_validSymmetries_symmetriesParsed() {
+        assert_eq!(Symmetry::Horizontal, "Horizontal".parse().unwrap());
+        assert_eq!(Symmetry::Vertical, "Vertical".parse().unwrap());
+        assert_eq!(Symmetry::Both, "Both".parse().unwrap());
+        assert_eq!(Symmetry::Rotational(6), "Rotational:6".parse().unwrap());
+        assert_eq!(Symmetry::Rotational(64), "Rotational:64".parse().unwrap());
+    }
+
+    #[test]
+    fn fromStr_invalidSymmetries_errorReturned() {
+        assert!("Diagonal".parse::<Symmetry>().is_err());
+        assert!("Rotational".parse::<Symmetry>().is_err());
+        assert!("Rotational:1".parse::<Symmetry>().is_err());
+        assert!("Rotational:65".parse::<Symmetry>().is_err());
+        assert!("Horizontal:2".parse::<Symmetry>().is_err());
+    }
+
+    #[test]
+    fn transform_bothSymmetry_allQuartersCovered() {
+        let symmetry = Symmetry::Both;
+
+        let pixels = symmetry
+            .transformations()
+            .iter()
+            .map(|transformation| transformation.apply(1, 0, 4, 3))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Some((1, 0)), Some((2, 0)), Some((1, 2)), Some((2, 2))],
+            pixels
+        );
+    }
+
+    #[test]
+    fn transform_fourFoldRotation_pixelRotatedAroundCenter() {
+        let symmetry = Symmetry::Rotational(4);
+
+        let pixels = symmetry
+            .transformations()
+            .iter()
+            .map(|transformation| transformation.apply(0, 0, 4, 4))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Some((0, 0)), Some((3, 0)), Some((3, 3)), Some((0, 3))],
+            pixels
+        );
+    }
+}
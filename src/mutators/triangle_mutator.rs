@@ -1,139 +1,100 @@
 use crate::{
-    models::{Image, Pixel},
+    geometry::{Point, Triangle},
+    models::Image,
     util::Random,
+    ColorMode, RunConfiguration,
 };
 
-use super::Mutator;
-
-fn get_dx(vertices: &[Point; 3]) -> (f64, f64, f64) {
-    (
-        (vertices[2].x - vertices[0].x) as f64 / (vertices[2].y - vertices[0].y + 1) as f64,
-        (vertices[1].x - vertices[0].x) as f64 / (vertices[1].y - vertices[0].y + 1) as f64,
-        (vertices[2].x - vertices[1].x) as f64 / (vertices[2].y - vertices[1].y + 1) as f64,
-    )
-}
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-struct Point {
-    x: isize,
-    y: isize,
-}
+use super::{MutationContext, Mutator, ShapeStyle};
 
 /// This mutator mutates the given specimen by generating a random triangle with random background color.
 #[derive(Debug, Default)]
-pub struct TriangleMutator;
+pub struct TriangleMutator {
+    style: ShapeStyle,
+}
 
 impl TriangleMutator {
-    fn get_random_point(&self, random: &mut Random, image: &Image) -> Point {
-        let x = random.get_random(0isize, image.width() as isize - 1);
-        let y = random.get_random(0isize, image.height() as isize - 1);
+    /// Creates a new mutator which paints triangles with the given style.
+    #[must_use]
+    pub fn new(style: ShapeStyle) -> Self {
+        Self { style }
+    }
 
-        Point { x, y }
+    fn get_random_point(
+        &self,
+        random: &mut Random,
+        min: (usize, usize),
+        max: (usize, usize),
+    ) -> Point {
+        let x = random.get_random(min.0, max.0);
+        let y = random.get_random(min.1, max.1);
+
+        // Vertices are placed in the centers of their pixels.
+        Point::new(x as f64 + 0.5, y as f64 + 0.5)
     }
 
-    fn get_triangle_vertices(&self, random: &mut Random, image: &Image) -> [Point; 3] {
-        let mut vertices = Vec::new();
+    fn get_random_triangle(
+        &self,
+        random: &mut Random,
+        image: &Image,
+        position: Point,
+        max_size: f64,
+    ) -> Triangle {
+        let image_width = image.width();
+        let image_height = image.height();
+
+        // Vertices are placed in the centers of their pixels.
+        let x = (position.x as usize).min(image_width - 1);
+        let y = (position.y as usize).min(image_height - 1);
+        let first = Point::new(x as f64 + 0.5, y as f64 + 0.5);
+
+        // Remaining vertices lie close enough to the first one. The extent of at least 2 pixels leaves room for 3
+        // distinct vertices.
+        let extent = ((image_width.max(image_height) as f64 * max_size).ceil() as usize).max(2);
+        let min = (x.saturating_sub(extent), y.saturating_sub(extent));
+        let max = (
+            (x + extent + 1).min(image_width),
+            (y + extent + 1).min(image_height),
+        );
+
+        let mut vertices = vec![first];
 
         while vertices.len() != 3 {
-            let point = self.get_random_point(random, image);
+            let point = self.get_random_point(random, min, max);
 
             if !vertices.contains(&point) {
                 vertices.push(point);
             }
         }
 
-        // The points must be sorted vertically. Required by the algorithm.
-        vertices.sort_by(|p1, p2| p1.y.cmp(&p2.y));
-
-        [vertices[0], vertices[1], vertices[2]]
-    }
-
-    fn draw_triangle<F>(&self, random: &mut Random, image: &mut Image, pixel_mutator: F)
-    where
-        F: Fn(&mut Pixel),
-    {
-        let vertices = self.get_triangle_vertices(random, image);
-
-        let (dx_far, dx_upper, dx_low) = get_dx(&vertices);
-
-        let image_width = image.width() as isize;
-        let image_height = image.height() as isize;
-
-        let mut xf = vertices[0].x as f64;
-        let mut xt = vertices[0].x as f64 + dx_upper;
-
-        let mut y = vertices[0].y;
-        while y
-            <= if vertices[2].y > image_height - 1 {
-                image_height - 1
-            } else {
-                vertices[2].y
-            }
-        {
-            if y >= 0 {
-                let mut x = if xf > 0f64 { xf as isize } else { 0 };
-                while x
-                    <= if xt < image_width as f64 {
-                        xt as isize
-                    } else {
-                        image_width - 1
-                    }
-                {
-                    let idx = (y * image_width + x) as usize;
-                    pixel_mutator(&mut image[idx]);
-
-                    x += 1;
-                }
-
-                let mut x = if xf < image_width as f64 {
-                    xf as isize
-                } else {
-                    image_width - 1
-                };
-                while x >= if xt > 0f64 { xt as isize } else { 0 } {
-                    let idx = (y * image_width + x) as usize;
-                    pixel_mutator(&mut image[idx]);
-
-                    x -= 1;
-                }
-            }
-            xf += dx_far;
-
-            if y < vertices[1].y {
-                xt += dx_upper;
-            } else {
-                xt += dx_low;
-            }
-
-            y += 1;
-        }
+        Triangle::new(vertices[0], vertices[1], vertices[2])
     }
 }
 
 impl Mutator for TriangleMutator {
     fn mutate_rgb(&self, image: &mut Image) {
-        let mut random = Random::default();
-
-        let r = random.get_random(0u8, 255);
-        let g = random.get_random(0u8, 255);
-        let b = random.get_random(0u8, 255);
-
-        let rgb_pixel_mutator = move |p: &mut Pixel| {
-            p.r(r);
-            p.g(g);
-            p.b(b);
-        };
-
-        self.draw_triangle(&mut random, image, rgb_pixel_mutator);
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Rgb)),
+        );
     }
 
     fn mutate_grayscale(&self, image: &mut Image) {
-        let mut random = Random::default();
+        self.mutate(
+            image,
+            &mut MutationContext::new(&RunConfiguration::new(ColorMode::Grayscale)),
+        );
+    }
 
-        let grayscale = random.get_random(0u8, 255);
+    fn mutate(&self, image: &mut Image, context: &mut MutationContext) {
+        let max_size = self.style.get_max_size(context);
+        let position = self.style.get_random_position(context, image);
+        let triangle = self.get_random_triangle(context.get_random(), image, position, max_size);
 
-        let grayscale_pixel_mutator = move |p: &mut Pixel| p.set_grayscale(grayscale);
+        self.style.draw_closed(&triangle, image, context);
+    }
 
-        self.draw_triangle(&mut random, image, grayscale_pixel_mutator);
+    fn uses_error_map(&self) -> bool {
+        self.style.uses_error_map()
     }
 }
@@ -0,0 +1,88 @@
+use crate::{models::Palette, mutators::BlendMode, ColorMode};
+
+/// This struct describes settings of the simulation, which stay the same for every mutation.
+///
+/// Mutators can access it through [MutationContext::get_configuration].
+///
+/// [MutationContext::get_configuration]: crate::mutators::MutationContext::get_configuration
+#[derive(Debug, Clone)]
+pub struct RunConfiguration {
+    color_mode: ColorMode,
+    generation_size: usize,
+    palette: Option<Palette>,
+    blend_mode: BlendMode,
+    error_guided: bool,
+}
+
+impl RunConfiguration {
+    /// Creates a configuration of a simulation running in the given color mode.
+    #[must_use]
+    pub fn new(color_mode: ColorMode) -> Self {
+        Self {
+            color_mode,
+            generation_size: 100,
+            palette: None,
+            blend_mode: BlendMode::Normal,
+            error_guided: false,
+        }
+    }
+
+    /// Sets the number of specimens in every generation.
+    pub fn set_generation_size(&mut self, generation_size: usize) {
+        self.generation_size = generation_size;
+    }
+
+    /// Restricts colors of shapes to the given palette.
+    ///
+    /// Every pixel painted by a mutator is snapped to the palette after compositing.
+    /// Colors of the palette must already be converted to the color mode of the simulation.
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = Some(palette);
+    }
+
+    /// Sets how colors of shapes are combined with colors of specimens underneath them, unless the style of a mutator
+    /// overrides it.
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Sets whether the error map of the best specimen is computed for every generation.
+    ///
+    /// The error map is needed only by [Placement::ErrorGuided], so computing it is skipped by default.
+    ///
+    /// [Placement::ErrorGuided]: crate::mutators::Placement::ErrorGuided
+    pub fn set_error_guided(&mut self, error_guided: bool) {
+        self.error_guided = error_guided;
+    }
+
+    /// Returns the color mode in which specimens are mutated.
+    pub fn get_color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Returns the number of specimens in every generation.
+    pub fn get_generation_size(&self) -> usize {
+        self.generation_size
+    }
+
+    /// Returns the palette which colors of shapes are restricted to, if there is one.
+    pub fn get_palette(&self) -> Option<&Palette> {
+        self.palette.as_ref()
+    }
+
+    /// Returns how colors of shapes are combined with colors of specimens underneath them.
+    pub fn get_blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Returns whether the error map of the best specimen is computed for every generation.
+    pub fn get_error_guided(&self) -> bool {
+        self.error_guided
+    }
+}
+
+impl Default for RunConfiguration {
+    fn default() -> Self {
+        Self::new(ColorMode::Rgb)
+    }
+}
@@ -18,13 +18,20 @@ impl Random {
         Uniform::new(min, max).sample(&mut self.rng)
     }
 
+    /// Creates a generator which always produces the same sequence of numbers for the same seed.
+    #[must_use]
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
     pub fn get_rng(&mut self) -> &mut StdRng {
         &mut self.rng
     }
 }
 
 impl Default for Random {
-    #[must_use]
     fn default() -> Self {
         Self {
             rng: rand::rngs::StdRng::from_entropy(),